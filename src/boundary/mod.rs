@@ -0,0 +1,175 @@
+//! Keeping entities inside of the arena by wrapping them around, bouncing them off of the walls or
+//! deleting them once they leave.
+
+use std::cell::{Ref, RefMut};
+
+use crate::components::CastComponents;
+use crate::data_types::boundary::{Boundary, BoundaryPolicy};
+use crate::data_types::point::Point;
+use crate::data_types::rect::Rect;
+use crate::errors::{BbEcsError, Result};
+use crate::resources::resource::ResourceCast;
+use crate::world::{World, ENTITY_ID, TO_BE_DELETED};
+
+/// System that applies the `Boundary` of every entity with a location and boundary component using
+/// the arena `Rect` stored as a resource. Bouncing entities also need a velocity component to be
+/// bounced, without one they are only kept inside. Despawning entities are marked to be deleted on
+/// the next `World::update`.
+/// ```
+/// use bbecs::boundary::BoundarySystem;
+/// use bbecs::data_types::boundary::Boundary;
+/// use bbecs::data_types::point::Point;
+/// use bbecs::data_types::rect::Rect;
+/// use bbecs::world::{World, WorldMethods};
+///
+/// # fn main() -> eyre::Result<()> {
+/// let mut world = World::new();
+/// world.register("location")?;
+/// world.register("velocity")?;
+/// world.register("boundary")?;
+/// world.add_resource(
+///     "arena".to_owned(),
+///     Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+/// );
+/// world
+///     .spawn_entity()?
+///     .with_component("location", Point::new(805.0, 300.0))?
+///     .with_component("velocity", Point::new(5.0, 0.0))?
+///     .with_component("boundary", Boundary::wrap(0.0))?;
+///
+/// BoundarySystem::new().run(&world)?;
+/// assert_eq!(*world.get::<Point>(0, "location")?, Point::new(5.0, 300.0));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundarySystem {
+    pub location: String,
+    pub velocity: String,
+    pub boundary: String,
+    /// Name of the `Rect` resource with the bounds of the arena
+    pub arena: String,
+}
+
+impl BoundarySystem {
+    /// Create the system using the "location", "velocity" and "boundary" components and the "arena"
+    /// resource
+    pub fn new() -> Self {
+        Self {
+            location: "location".to_owned(),
+            velocity: "velocity".to_owned(),
+            boundary: "boundary".to_owned(),
+            arena: "arena".to_owned(),
+        }
+    }
+
+    pub fn run(&self, world: &World) -> Result<()> {
+        let arena = {
+            let resource = world.borrow_resource(self.arena.as_str())?;
+            let arena: &Rect = resource
+                .cast()
+                .map_err(|error| error.with_name(&self.arena))?;
+            *arena
+        };
+        let query = world.query(vec![
+            self.location.as_str(),
+            self.boundary.as_str(),
+            TO_BE_DELETED,
+            ENTITY_ID,
+        ])?;
+        let locations = query.get(self.location.as_str()).unwrap();
+        let boundaries = query.get(self.boundary.as_str()).unwrap();
+        let to_be_deleted = query.get(TO_BE_DELETED).unwrap();
+        let entity_ids = query.get(ENTITY_ID).unwrap();
+
+        for (index, location) in locations.iter().enumerate() {
+            let mut location: RefMut<Point> = location.try_borrow_mut(&self.location)?;
+            let boundary: Ref<Boundary> = boundaries[index].try_borrow(&self.boundary)?;
+
+            match boundary.policy {
+                BoundaryPolicy::Wrap => {
+                    wrap(&mut location, &grow(&arena, boundary.margin));
+                }
+                BoundaryPolicy::Bounce => {
+                    let area = grow(&arena, -boundary.margin);
+                    let entity_id: Ref<u32> = entity_ids[index].try_borrow(ENTITY_ID)?;
+                    match world.get_mut::<Point>(*entity_id, self.velocity.as_str()) {
+                        Ok(mut velocity) => bounce(&mut location, &mut velocity, &area),
+                        Err(
+                            BbEcsError::NoSuchComponent { .. }
+                            | BbEcsError::ComponentNotFound { .. },
+                        ) => bounce(&mut location, &mut Point::new(0.0, 0.0), &area),
+                        Err(error) => return Err(error),
+                    }
+                }
+                BoundaryPolicy::Despawn => {
+                    if is_outside(&location, &grow(&arena, boundary.margin)) {
+                        let mut to_be_deleted: RefMut<bool> =
+                            to_be_deleted[index].try_borrow_mut(TO_BE_DELETED)?;
+                        *to_be_deleted = true;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for BoundarySystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The arena grown by the margin on every side, a negative margin shrinks it
+fn grow(arena: &Rect, margin: f32) -> Rect {
+    Rect::new(
+        arena.position - Point::new(margin, margin),
+        arena.size + Point::new(margin, margin) * 2.0,
+    )
+}
+
+/// Wrap the location around to the other side of the area. An area without any width or height
+/// has nowhere to wrap to, so the location is moved to its center on that axis instead.
+fn wrap(location: &mut Point, area: &Rect) {
+    if location.x < area.left() || location.x > area.right() {
+        location.x = if area.width() > 0.0 {
+            area.left() + (location.x - area.left()).rem_euclid(area.width())
+        } else {
+            area.center().x
+        };
+    }
+    if location.y < area.top() || location.y > area.bottom() {
+        location.y = if area.height() > 0.0 {
+            area.top() + (location.y - area.top()).rem_euclid(area.height())
+        } else {
+            area.center().y
+        };
+    }
+}
+
+fn bounce(location: &mut Point, velocity: &mut Point, area: &Rect) {
+    if location.x < area.left() {
+        location.x = area.left();
+        velocity.x = velocity.x.abs();
+    } else if location.x > area.right() {
+        location.x = area.right();
+        velocity.x = -velocity.x.abs();
+    }
+
+    if location.y < area.top() {
+        location.y = area.top();
+        velocity.y = velocity.y.abs();
+    } else if location.y > area.bottom() {
+        location.y = area.bottom();
+        velocity.y = -velocity.y.abs();
+    }
+}
+
+fn is_outside(location: &Point, area: &Rect) -> bool {
+    location.x < area.left()
+        || location.x > area.right()
+        || location.y < area.top()
+        || location.y > area.bottom()
+}
@@ -0,0 +1,203 @@
+//! Collision detection between entities with a `Collider` and a location component. Potential
+//! collisions are found by sorting the bounding rects of the colliders along the x axis and only
+//! checking the shapes of colliders that overlap on that axis. Colliders whose layers and masks
+//! don't allow them to collide are skipped before their shapes are checked.
+
+pub mod response;
+
+use std::cell::Ref;
+use std::collections::BTreeSet;
+
+use crate::components::CastComponents;
+use crate::data_types::collider::Collider;
+use crate::data_types::point::Point;
+use crate::data_types::rect::Rect;
+use crate::errors::Result;
+use crate::world::{World, ENTITY_ID};
+
+/// Something that changed between two entities since the last time the collision system ran. The
+/// smaller entity id is always first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CollisionEvent {
+    /// The entities started overlapping
+    Started { first: u32, second: u32 },
+    /// The entities stopped overlapping, or one of them no longer has a collider
+    Ended { first: u32, second: u32 },
+}
+
+impl CollisionEvent {
+    /// The ids of both entities in the event
+    pub fn entity_ids(&self) -> (u32, u32) {
+        match *self {
+            CollisionEvent::Started { first, second } | CollisionEvent::Ended { first, second } => {
+                (first, second)
+            }
+        }
+    }
+
+    /// Whether the entity is one of the entities in the event
+    pub fn involves(&self, entity_id: u32) -> bool {
+        let (first, second) = self.entity_ids();
+        first == entity_id || second == entity_id
+    }
+}
+
+/// System that checks every entity with a collider and a location component for collisions. It
+/// remembers which entities were colliding so that each run only reports the collisions that
+/// started or ended.
+/// ```
+/// use bbecs::collision::{CollisionEvent, CollisionSystem};
+/// use bbecs::data_types::collider::Collider;
+/// use bbecs::data_types::point::Point;
+/// use bbecs::world::{World, WorldMethods};
+///
+/// # fn main() -> eyre::Result<()> {
+/// let mut world = World::new();
+/// world.register("location")?;
+/// world.register("collider")?;
+/// world
+///     .spawn_entity()?
+///     .with_component("location", Point::new(0.0, 0.0))?
+///     .with_component("collider", Collider::circle(5.0))?;
+/// world
+///     .spawn_entity()?
+///     .with_component("location", Point::new(8.0, 0.0))?
+///     .with_component("collider", Collider::circle(5.0))?;
+///
+/// let mut collision_system = CollisionSystem::new();
+/// let events = collision_system.run(&world)?;
+/// assert_eq!(events, vec![CollisionEvent::Started { first: 0, second: 1 }]);
+/// assert!(collision_system.run(&world)?.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollisionSystem {
+    pub location: String,
+    pub collider: String,
+    colliding: BTreeSet<(u32, u32)>,
+}
+
+impl CollisionSystem {
+    /// Create the system using the "location" and "collider" components
+    pub fn new() -> Self {
+        Self {
+            location: "location".to_owned(),
+            collider: "collider".to_owned(),
+            colliding: BTreeSet::new(),
+        }
+    }
+
+    /// Check for collisions and return the collisions that ended followed by the collisions that
+    /// started since the last run
+    pub fn run(&mut self, world: &World) -> Result<Vec<CollisionEvent>> {
+        let colliders = self.collect_colliders(world)?;
+        let colliding = find_collisions(colliders);
+
+        let ended = self
+            .colliding
+            .difference(&colliding)
+            .map(|&(first, second)| CollisionEvent::Ended { first, second });
+        let started = colliding
+            .difference(&self.colliding)
+            .map(|&(first, second)| CollisionEvent::Started { first, second });
+        let events = ended.chain(started).collect();
+
+        self.colliding = colliding;
+        Ok(events)
+    }
+
+    /// Whether the entities were colliding the last time the system ran
+    pub fn is_colliding(&self, entity_id: u32, other_entity_id: u32) -> bool {
+        self.colliding
+            .contains(&ordered_pair(entity_id, other_entity_id))
+    }
+
+    /// Every pair of entities that were colliding the last time the system ran, with the smaller
+    /// entity id first
+    pub fn collisions(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.colliding.iter().copied()
+    }
+
+    /// The entity id of every collider in the world along with the collider moved to the location
+    /// of the entity
+    fn collect_colliders(&self, world: &World) -> Result<Vec<(u32, Collider)>> {
+        let query = world.query(vec![
+            self.location.as_str(),
+            self.collider.as_str(),
+            ENTITY_ID,
+        ])?;
+        let locations = query.get(self.location.as_str()).unwrap();
+        let colliders = query.get(self.collider.as_str()).unwrap();
+        let entity_ids = query.get(ENTITY_ID).unwrap();
+
+        let mut placed_colliders = Vec::with_capacity(locations.len());
+        for (index, location) in locations.iter().enumerate() {
+            let location: Ref<Point> = location.try_borrow(&self.location)?;
+            let collider: Ref<Collider> = colliders[index].try_borrow(&self.collider)?;
+            let entity_id: Ref<u32> = entity_ids[index].try_borrow(ENTITY_ID)?;
+            let placed_collider = Collider {
+                shape: collider.shape_at(*location),
+                ..*collider
+            };
+            placed_colliders.push((*entity_id, placed_collider));
+        }
+
+        Ok(placed_colliders)
+    }
+}
+
+impl Default for CollisionSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The entity ids of every pair of colliders that overlap, with the smaller entity id first
+fn find_collisions(colliders: Vec<(u32, Collider)>) -> BTreeSet<(u32, u32)> {
+    let (entity_ids, colliders): (Vec<u32>, Vec<Collider>) = colliders.into_iter().unzip();
+
+    overlapping_pairs(&colliders)
+        .into_iter()
+        .map(|(index, other_index)| ordered_pair(entity_ids[index], entity_ids[other_index]))
+        .collect()
+}
+
+/// Sweep and prune: after sorting by the left edge, each collider only needs to be checked against
+/// the colliders that start before it ends. Returns the indexes of the overlapping colliders.
+fn overlapping_pairs(colliders: &[Collider]) -> Vec<(usize, usize)> {
+    let mut sorted_bounds: Vec<(usize, Rect)> = colliders
+        .iter()
+        .map(|collider| collider.shape.bounding_rect())
+        .enumerate()
+        .collect();
+    sorted_bounds.sort_by(|a, b| a.1.left().total_cmp(&b.1.left()));
+
+    let mut pairs = vec![];
+    for (position, (index, bounds)) in sorted_bounds.iter().enumerate() {
+        for (other_index, other_bounds) in &sorted_bounds[position + 1..] {
+            if other_bounds.left() >= bounds.right() {
+                break;
+            }
+
+            let collider = &colliders[*index];
+            let other_collider = &colliders[*other_index];
+            if collider.can_collide_with(other_collider)
+                && bounds.intersects(other_bounds)
+                && collider.shape.overlaps(&other_collider.shape)
+            {
+                pairs.push((*index, *other_index));
+            }
+        }
+    }
+
+    pairs
+}
+
+fn ordered_pair(entity_id: u32, other_entity_id: u32) -> (u32, u32) {
+    if entity_id < other_entity_id {
+        (entity_id, other_entity_id)
+    } else {
+        (other_entity_id, entity_id)
+    }
+}
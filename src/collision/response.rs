@@ -0,0 +1,177 @@
+use std::cell::{Ref, RefMut};
+
+use crate::components::CastComponents;
+use crate::data_types::collider::{Collider, ColliderShape};
+use crate::data_types::point::Point;
+use crate::errors::Result;
+use crate::world::World;
+
+use super::overlapping_pairs;
+
+/// Physics step that pushes overlapping circle colliders apart and bounces them off of each other
+/// by changing their velocity. Entities need a location, velocity, collider, mass and restitution
+/// component, where a mass of 0.0 makes the entity immovable like a wall and a restitution of 0.0
+/// stops it dead while 1.0 bounces it back with all of its speed. Colliders that aren't circles
+/// are ignored.
+/// ```
+/// use bbecs::collision::response::CollisionResponseSystem;
+/// use bbecs::data_types::collider::Collider;
+/// use bbecs::data_types::point::Point;
+/// use bbecs::world::{World, WorldMethods};
+///
+/// # fn main() -> eyre::Result<()> {
+/// let mut world = World::new();
+/// world.register("location")?;
+/// world.register("velocity")?;
+/// world.register("collider")?;
+/// world.register("mass")?;
+/// world.register("restitution")?;
+/// for (x, velocity_x) in [(0.0, 1.0), (8.0, -1.0)].iter() {
+///     world
+///         .spawn_entity()?
+///         .with_component("location", Point::new(*x, 0.0))?
+///         .with_component("velocity", Point::new(*velocity_x, 0.0))?
+///         .with_component("collider", Collider::circle(5.0))?
+///         .with_component("mass", 1.0_f32)?
+///         .with_component("restitution", 1.0_f32)?;
+/// }
+///
+/// CollisionResponseSystem::new().run(&world)?;
+/// assert_eq!(*world.get::<Point>(0, "location")?, Point::new(-1.0, 0.0));
+/// assert_eq!(*world.get::<Point>(0, "velocity")?, Point::new(-1.0, 0.0));
+/// assert_eq!(*world.get::<Point>(1, "velocity")?, Point::new(1.0, 0.0));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollisionResponseSystem {
+    pub location: String,
+    pub velocity: String,
+    pub collider: String,
+    pub mass: String,
+    pub restitution: String,
+}
+
+/// The state of one entity while the collisions are resolved
+struct Body {
+    location: Point,
+    velocity: Point,
+    collider: Collider,
+    inverse_mass: f32,
+    restitution: f32,
+}
+
+impl CollisionResponseSystem {
+    /// Create the system using the "location", "velocity", "collider", "mass" and "restitution"
+    /// components
+    pub fn new() -> Self {
+        Self {
+            location: "location".to_owned(),
+            velocity: "velocity".to_owned(),
+            collider: "collider".to_owned(),
+            mass: "mass".to_owned(),
+            restitution: "restitution".to_owned(),
+        }
+    }
+
+    pub fn run(&self, world: &World) -> Result<()> {
+        let query = world.query(vec![
+            self.location.as_str(),
+            self.velocity.as_str(),
+            self.collider.as_str(),
+            self.mass.as_str(),
+            self.restitution.as_str(),
+        ])?;
+        let locations = query.get(self.location.as_str()).unwrap();
+        let velocities = query.get(self.velocity.as_str()).unwrap();
+        let colliders = query.get(self.collider.as_str()).unwrap();
+        let masses = query.get(self.mass.as_str()).unwrap();
+        let restitutions = query.get(self.restitution.as_str()).unwrap();
+
+        let mut bodies = Vec::with_capacity(locations.len());
+        for (index, location) in locations.iter().enumerate() {
+            let location: Ref<Point> = location.try_borrow(&self.location)?;
+            let velocity: Ref<Point> = velocities[index].try_borrow(&self.velocity)?;
+            let collider: Ref<Collider> = colliders[index].try_borrow(&self.collider)?;
+            let mass: Ref<f32> = masses[index].try_borrow(&self.mass)?;
+            let restitution: Ref<f32> = restitutions[index].try_borrow(&self.restitution)?;
+
+            bodies.push(Body {
+                location: *location,
+                velocity: *velocity,
+                collider: *collider,
+                inverse_mass: if *mass > 0.0 { 1.0 / *mass } else { 0.0 },
+                restitution: *restitution,
+            });
+        }
+
+        let placed_colliders: Vec<Collider> = bodies
+            .iter()
+            .map(|body| Collider {
+                shape: body.collider.shape_at(body.location),
+                ..body.collider
+            })
+            .collect();
+        for (index, other_index) in overlapping_pairs(&placed_colliders) {
+            resolve_collision(&mut bodies, index, other_index);
+        }
+
+        for (index, body) in bodies.iter().enumerate() {
+            let mut location: RefMut<Point> = locations[index].try_borrow_mut(&self.location)?;
+            let mut velocity: RefMut<Point> = velocities[index].try_borrow_mut(&self.velocity)?;
+            *location = body.location;
+            *velocity = body.velocity;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for CollisionResponseSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Separate the bodies in proportion to their inverse mass and apply an impulse along the contact
+/// normal if they are moving towards each other. The contact is checked again with the current
+/// locations since resolving earlier pairs might have already pushed these bodies apart.
+fn resolve_collision(bodies: &mut [Body], index: usize, other_index: usize) {
+    let body = &bodies[index];
+    let other_body = &bodies[other_index];
+    let total_inverse_mass = body.inverse_mass + other_body.inverse_mass;
+    if total_inverse_mass == 0.0 {
+        return;
+    }
+
+    let contact = match (
+        body.collider.shape_at(body.location),
+        other_body.collider.shape_at(other_body.location),
+    ) {
+        (ColliderShape::Circle(circle), ColliderShape::Circle(other_circle)) => {
+            match circle.contact_circle(&other_circle) {
+                Some(contact) => contact,
+                None => return,
+            }
+        }
+        _ => return,
+    };
+
+    let correction = contact.normal * (contact.penetration / total_inverse_mass);
+    let relative_velocity = other_body.velocity - body.velocity;
+    let approaching_speed = relative_velocity.dot(&contact.normal);
+    let restitution = body.restitution.min(other_body.restitution);
+    let impulse = if approaching_speed < 0.0 {
+        contact.normal * (-(1.0 + restitution) * approaching_speed / total_inverse_mass)
+    } else {
+        Point::new(0.0, 0.0)
+    };
+
+    let body = &mut bodies[index];
+    body.location -= correction * body.inverse_mass;
+    body.velocity -= impulse * body.inverse_mass;
+
+    let other_body = &mut bodies[other_index];
+    other_body.location += correction * other_body.inverse_mass;
+    other_body.velocity += impulse * other_body.inverse_mass;
+}
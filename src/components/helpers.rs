@@ -1,39 +1,44 @@
 #[macro_export]
 /// Helper to query for components in the world. We have to give a query variable that will last longer
-/// than the macro for this to work.
+/// than the macro for this to work. Errors from the query are returned with `?`, and the components
+/// can be borrowed with `try_borrow` and `try_borrow_mut` so that borrow conflicts are returned as
+/// a `BbEcsError::BorrowConflict` instead of panicking. Tags can be queried to filter the entities,
+/// their lists are always empty.
 ///
 /// ```
 /// use bbecs::query;
-/// use bbecs::world::{World, ENTITY_ID, WorldMethods, DataWrapper};
+/// use bbecs::world::{World, ENTITY_ID, WorldMethods};
 /// use bbecs::components::CastComponents;
+/// use std::cell::Ref;
 ///
+/// # fn main() -> eyre::Result<()> {
 /// // creating the world and adding an entity for us to query later
 /// let mut world = World::new();
-///     world.register("size").unwrap();
+///     world.register("size")?;
 ///     world
-///         .spawn_entity()
-///         .unwrap()
-///         .with_component("size", 15.0_f32)
-///         .unwrap();
+///         .spawn_entity()?
+///         .with_component("size", 15.0_f32)?;
 ///
 /// // Now we are querying for the size and an entity id which is automatically added to all entities
 /// let query;
 /// let (sizes, entity_ids) = query!(world, query, "size", ENTITY_ID);
 ///
 /// for (index, size) in sizes.iter().enumerate() {
-///     let size: &DataWrapper<f32> = size.cast().unwrap();
-///     let entity_id: &DataWrapper<u32> = entity_ids[index].cast().unwrap();
+///     let size: Ref<f32> = size.try_borrow("size")?;
+///     let entity_id: Ref<u32> = entity_ids[index].try_borrow(ENTITY_ID)?;
 ///
-///     assert_eq!(*size.borrow(), 15.0);
-///     assert_eq!(*entity_id.borrow(), 0);
+///     assert_eq!(*size, 15.0);
+///     assert_eq!(*entity_id, 0);
 /// }
+/// # Ok(())
+/// # }
 /// ```
 macro_rules! query {
     ($world:expr, $query:expr, $($name:expr),*) => {{
-        $query = $world.query(vec![$($name,)*]).unwrap();
+        $query = $world.query(vec![$($name,)*])?;
 
         (
-            $($query.get($name).unwrap(),)*
+            $($query.try_get($name)?,)*
         )
     }};
 }
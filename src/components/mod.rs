@@ -1,14 +1,25 @@
 pub mod helpers;
 
-use std::cell::RefCell;
+use std::cell::{Ref, RefCell, RefMut};
 use std::rc::Rc;
 
-use eyre::{bail, Result};
+#[cfg(feature = "ggez")]
 use ggez::audio::SoundData;
+#[cfg(feature = "ggez")]
 use ggez::event::KeyCode;
-use ggez::graphics::{Color, Mesh, Text, TextFragment};
+#[cfg(feature = "ggez")]
+use ggez::graphics::{Color as GgezColor, Mesh, Text, TextFragment};
 
+use crate::data_types::boundary::Boundary;
+use crate::data_types::circle::Circle;
+use crate::data_types::collider::Collider;
+use crate::data_types::color::Color;
+use crate::data_types::entity_ref::EntityRef;
 use crate::data_types::point::Point;
+use crate::data_types::rect::Rect;
+use crate::data_types::timer::Timer;
+use crate::data_types::transform::Transform;
+use crate::errors::{BbEcsError, Result};
 
 macro_rules! impl_component_data_cast {
     ($new_type:ty, $arm:ident) => {
@@ -17,11 +28,11 @@ macro_rules! impl_component_data_cast {
                 if let ComponentData::$arm(value) = self {
                     Ok(value)
                 } else {
-                    bail!(
-                        "Error casting component from {:?} to {}",
-                        self,
-                        stringify!($new_type)
-                    )
+                    Err(BbEcsError::CastingComponents {
+                        name: None,
+                        expected: stringify!($new_type),
+                        found: self.type_name(),
+                    })
                 }
             }
         }
@@ -30,51 +41,162 @@ macro_rules! impl_component_data_cast {
 
 pub trait CastComponents<T> {
     fn cast(&self) -> Result<&Rc<RefCell<T>>>;
+
+    /// Cast the component and immutably borrow it. If the component is already mutably borrowed
+    /// a `BbEcsError::BorrowConflict` with the given name is returned instead of panicking, and
+    /// casting errors also carry the name.
+    fn try_borrow(&self, name: &str) -> Result<Ref<'_, T>> {
+        self.cast()
+            .map_err(|error| error.with_name(name))?
+            .try_borrow()
+            .map_err(|_| BbEcsError::BorrowConflict {
+                name: name.to_owned(),
+            })
+    }
+
+    /// Cast the component and mutably borrow it. If the component is already borrowed a
+    /// `BbEcsError::BorrowConflict` with the given name is returned instead of panicking, and
+    /// casting errors also carry the name.
+    fn try_borrow_mut(&self, name: &str) -> Result<RefMut<'_, T>> {
+        self.cast()
+            .map_err(|error| error.with_name(name))?
+            .try_borrow_mut()
+            .map_err(|_| BbEcsError::BorrowConflict {
+                name: name.to_owned(),
+            })
+    }
 }
 
 /// These components are used to store data into the world. Each of the components contains
 /// a vector of the appropriate data. Generally consumers of this library will not need to
 /// call Components directly. However the methods attached to components will be used to
 /// extract the data.
+///
+/// The ggez variants are only available when the `ggez` feature is enabled (it is by default).
 #[derive(Debug, Clone)]
 pub enum ComponentData {
     Point(Rc<RefCell<Point>>),
     F32(Rc<RefCell<f32>>),
+    /// Holds the bbecs `Color` since 0.4.0, the ggez color moved to `GgezColor`
     Color(Rc<RefCell<Color>>),
+    #[cfg(feature = "ggez")]
+    GgezColor(Rc<RefCell<GgezColor>>),
+    #[cfg(feature = "ggez")]
     Mesh(Rc<RefCell<Mesh>>),
     U32(Rc<RefCell<u32>>),
     Usize(Rc<RefCell<usize>>),
     Bool(Rc<RefCell<bool>>),
+    #[cfg(feature = "ggez")]
     GgezKeyCode(Rc<RefCell<KeyCode>>),
     Marker(Rc<RefCell<String>>),
+    EntityRef(Rc<RefCell<EntityRef>>),
+    PointList(Rc<RefCell<Vec<Point>>>),
+    F32List(Rc<RefCell<Vec<f32>>>),
+    Timer(Rc<RefCell<Timer>>),
+    Rect(Rc<RefCell<Rect>>),
+    Circle(Rc<RefCell<Circle>>),
+    Transform(Rc<RefCell<Transform>>),
+    Collider(Rc<RefCell<Collider>>),
+    Boundary(Rc<RefCell<Boundary>>),
+    #[cfg(feature = "ggez")]
     GgezText(Rc<RefCell<Text>>),
-    GgezSound(Rc<RefCell<ggez::audio::SoundData>>),
+    #[cfg(feature = "ggez")]
+    GgezSound(Rc<RefCell<SoundData>>),
+    #[cfg(feature = "ggez")]
     GgezTextFragment(Rc<RefCell<TextFragment>>),
 }
 
+impl ComponentData {
+    /// The name of the type stored in this component, used when reporting casting errors
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ComponentData::Point(_) => "Point",
+            ComponentData::F32(_) => "f32",
+            ComponentData::Color(_) => "Color",
+            #[cfg(feature = "ggez")]
+            ComponentData::GgezColor(_) => "GgezColor",
+            #[cfg(feature = "ggez")]
+            ComponentData::Mesh(_) => "Mesh",
+            ComponentData::U32(_) => "u32",
+            ComponentData::Usize(_) => "usize",
+            ComponentData::Bool(_) => "bool",
+            #[cfg(feature = "ggez")]
+            ComponentData::GgezKeyCode(_) => "KeyCode",
+            ComponentData::Marker(_) => "String",
+            ComponentData::EntityRef(_) => "EntityRef",
+            ComponentData::PointList(_) => "Vec<Point>",
+            ComponentData::F32List(_) => "Vec<f32>",
+            ComponentData::Timer(_) => "Timer",
+            ComponentData::Rect(_) => "Rect",
+            ComponentData::Circle(_) => "Circle",
+            ComponentData::Transform(_) => "Transform",
+            ComponentData::Collider(_) => "Collider",
+            ComponentData::Boundary(_) => "Boundary",
+            #[cfg(feature = "ggez")]
+            ComponentData::GgezText(_) => "Text",
+            #[cfg(feature = "ggez")]
+            ComponentData::GgezSound(_) => "SoundData",
+            #[cfg(feature = "ggez")]
+            ComponentData::GgezTextFragment(_) => "TextFragment",
+        }
+    }
+}
+
 impl_component_data_cast!(Point, Point);
 impl_component_data_cast!(f32, F32);
 impl_component_data_cast!(Color, Color);
-impl_component_data_cast!(Mesh, Mesh);
 impl_component_data_cast!(u32, U32);
 impl_component_data_cast!(usize, Usize);
 impl_component_data_cast!(bool, Bool);
-impl_component_data_cast!(KeyCode, GgezKeyCode);
 impl_component_data_cast!(String, Marker);
+impl_component_data_cast!(EntityRef, EntityRef);
+impl_component_data_cast!(Vec<Point>, PointList);
+impl_component_data_cast!(Vec<f32>, F32List);
+impl_component_data_cast!(Timer, Timer);
+impl_component_data_cast!(Rect, Rect);
+impl_component_data_cast!(Circle, Circle);
+impl_component_data_cast!(Transform, Transform);
+impl_component_data_cast!(Collider, Collider);
+impl_component_data_cast!(Boundary, Boundary);
+
+#[cfg(feature = "ggez")]
+impl_component_data_cast!(GgezColor, GgezColor);
+#[cfg(feature = "ggez")]
+impl_component_data_cast!(Mesh, Mesh);
+#[cfg(feature = "ggez")]
+impl_component_data_cast!(KeyCode, GgezKeyCode);
+#[cfg(feature = "ggez")]
 impl_component_data_cast!(Text, GgezText);
+#[cfg(feature = "ggez")]
 impl_component_data_cast!(SoundData, GgezSound);
+#[cfg(feature = "ggez")]
 impl_component_data_cast!(TextFragment, GgezTextFragment);
 
 pub enum Component {
     Point,
     F32,
     Color,
+    #[cfg(feature = "ggez")]
+    GgezColor,
+    #[cfg(feature = "ggez")]
     Mesh,
     U32,
     Usize,
     Bool,
+    #[cfg(feature = "ggez")]
     GgezKeyCode,
     Marker,
+    EntityRef,
+    PointList,
+    F32List,
+    Timer,
+    Rect,
+    Circle,
+    Transform,
+    Collider,
+    Boundary,
+    #[cfg(feature = "ggez")]
     GgezText,
+    #[cfg(feature = "ggez")]
     GgezTextFragment,
 }
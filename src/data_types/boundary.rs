@@ -0,0 +1,39 @@
+/// What happens to an entity when it reaches the edge of the arena
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryPolicy {
+    /// Leave one side of the arena and come back in on the other side like in Asteroids
+    #[default]
+    Wrap,
+    /// Stay inside of the arena and bounce off of the walls
+    Bounce,
+    /// Get deleted once outside of the arena
+    Despawn,
+}
+
+/// Component that keeps an entity inside of the arena, applied by `BoundarySystem`. The margin is
+/// usually the radius of the entity: bouncing entities stay the margin away from the walls, while
+/// wrapping and despawning entities can go the margin past the edge so that they are fully off
+/// screen first.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Boundary {
+    pub policy: BoundaryPolicy,
+    pub margin: f32,
+}
+
+impl Boundary {
+    pub fn new(policy: BoundaryPolicy, margin: f32) -> Self {
+        Self { policy, margin }
+    }
+
+    pub fn wrap(margin: f32) -> Self {
+        Self::new(BoundaryPolicy::Wrap, margin)
+    }
+
+    pub fn bounce(margin: f32) -> Self {
+        Self::new(BoundaryPolicy::Bounce, margin)
+    }
+
+    pub fn despawn(margin: f32) -> Self {
+        Self::new(BoundaryPolicy::Despawn, margin)
+    }
+}
@@ -0,0 +1,179 @@
+use super::point::Point;
+use super::rect::Rect;
+
+/// How two overlapping shapes touch. The normal is a unit vector that points from the first shape
+/// towards the second, so moving the first shape by `-normal * penetration` or the second by
+/// `normal * penetration` separates them.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Contact {
+    pub normal: Point,
+    pub penetration: f32,
+}
+
+/// Circle around a center point, used for collisions between things like asteroids and bullets
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub center: Point,
+    pub radius: f32,
+}
+
+impl Circle {
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Whether the point is inside of the circle or on its edge
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::point::Point;
+    /// let circle = Circle::new(Point::new(0.0, 0.0), 5.0);
+    /// assert!(circle.contains_point(&Point::new(3.0, 4.0)));
+    /// assert!(!circle.contains_point(&Point::new(4.0, 4.0)));
+    /// ```
+    pub fn contains_point(&self, point: &Point) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    /// The smallest rect that contains the circle
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let circle = Circle::new(Point::new(10.0, 10.0), 5.0);
+    /// assert_eq!(circle.bounding_rect(), Rect::new(Point::new(5.0, 5.0), Point::new(10.0, 10.0)));
+    /// ```
+    pub fn bounding_rect(&self) -> Rect {
+        Rect::from_center(self.center, Point::new(self.radius, self.radius) * 2.0)
+    }
+
+    /// Whether the circles overlap, circles that only touch do not overlap
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::point::Point;
+    /// let circle = Circle::new(Point::new(0.0, 0.0), 5.0);
+    /// assert!(circle.overlaps_circle(&Circle::new(Point::new(8.0, 0.0), 5.0)));
+    /// assert!(!circle.overlaps_circle(&Circle::new(Point::new(10.0, 0.0), 5.0)));
+    /// ```
+    pub fn overlaps_circle(&self, other: &Circle) -> bool {
+        let radii = self.radius + other.radius;
+        self.center.distance_squared(&other.center) < radii * radii
+    }
+
+    /// Whether the circle overlaps the rect, touching the edge is not overlapping
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    /// assert!(Circle::new(Point::new(12.0, 5.0), 3.0).overlaps_rect(&rect));
+    /// assert!(!Circle::new(Point::new(13.0, 13.0), 3.0).overlaps_rect(&rect));
+    /// ```
+    pub fn overlaps_rect(&self, rect: &Rect) -> bool {
+        rect.closest_point(&self.center)
+            .distance_squared(&self.center)
+            < self.radius * self.radius
+    }
+
+    /// How far the circles overlap, 0.0 if they don't overlap
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::point::Point;
+    /// let circle = Circle::new(Point::new(0.0, 0.0), 5.0);
+    /// assert_eq!(circle.penetration_depth(&Circle::new(Point::new(8.0, 0.0), 5.0)), 2.0);
+    /// ```
+    pub fn penetration_depth(&self, other: &Circle) -> f32 {
+        self.contact_circle(other)
+            .map_or(0.0, |contact| contact.penetration)
+    }
+
+    /// The contact between the circles, None if they don't overlap. Circles with the same center
+    /// use a normal pointing right.
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::point::Point;
+    /// let circle = Circle::new(Point::new(0.0, 0.0), 5.0);
+    /// let contact = circle
+    ///     .contact_circle(&Circle::new(Point::new(0.0, 8.0), 5.0))
+    ///     .unwrap();
+    /// assert_eq!(contact.normal, Point::new(0.0, 1.0));
+    /// assert_eq!(contact.penetration, 2.0);
+    /// ```
+    pub fn contact_circle(&self, other: &Circle) -> Option<Contact> {
+        if !self.overlaps_circle(other) {
+            return None;
+        }
+
+        let difference = other.center - self.center;
+        let distance = difference.length();
+        let normal = if distance == 0.0 {
+            Point::new(1.0, 0.0)
+        } else {
+            difference / distance
+        };
+
+        Some(Contact {
+            normal,
+            penetration: self.radius + other.radius - distance,
+        })
+    }
+
+    /// The contact between the circle and the rect, None if they don't overlap. The normal points
+    /// from the circle towards the rect.
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let wall = Rect::new(Point::new(10.0, 0.0), Point::new(10.0, 100.0));
+    /// let contact = Circle::new(Point::new(8.0, 50.0), 5.0)
+    ///     .contact_rect(&wall)
+    ///     .unwrap();
+    /// assert_eq!(contact.normal, Point::new(1.0, 0.0));
+    /// assert_eq!(contact.penetration, 3.0);
+    /// ```
+    pub fn contact_rect(&self, rect: &Rect) -> Option<Contact> {
+        if !self.overlaps_rect(rect) {
+            return None;
+        }
+
+        let closest_point = rect.closest_point(&self.center);
+        let difference = closest_point - self.center;
+        let distance = difference.length();
+
+        // the center is inside of the rect or exactly on one of its edges, so there is no
+        // direction from the center to the closest point
+        if distance == 0.0 {
+            return Some(self.contact_rect_from_inside(rect));
+        }
+
+        Some(Contact {
+            normal: difference / distance,
+            penetration: self.radius - distance,
+        })
+    }
+
+    /// When the center is inside of the rect the circle is pushed out through the closest edge
+    fn contact_rect_from_inside(&self, rect: &Rect) -> Contact {
+        let edges = [
+            (self.center.x - rect.left(), Point::new(1.0, 0.0)),
+            (rect.right() - self.center.x, Point::new(-1.0, 0.0)),
+            (self.center.y - rect.top(), Point::new(0.0, 1.0)),
+            (rect.bottom() - self.center.y, Point::new(0.0, -1.0)),
+        ];
+        let (distance, normal) =
+            edges.iter().copied().fold(
+                edges[0],
+                |closest, edge| {
+                    if edge.0 < closest.0 {
+                        edge
+                    } else {
+                        closest
+                    }
+                },
+            );
+
+        Contact {
+            normal,
+            penetration: self.radius + distance,
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use super::circle::Circle;
+use super::collision_layers::CollisionLayers;
+use super::point::Point;
+use super::rect::Rect;
+
+/// The shape of a collider relative to the location of the entity, so a circle centered on
+/// (0.0, 0.0) is centered on the entity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColliderShape {
+    Circle(Circle),
+    Rect(Rect),
+}
+
+impl ColliderShape {
+    /// Move the shape so that it is relative to the location instead of the entity
+    pub fn translated(&self, location: Point) -> Self {
+        match *self {
+            ColliderShape::Circle(circle) => {
+                ColliderShape::Circle(Circle::new(circle.center + location, circle.radius))
+            }
+            ColliderShape::Rect(rect) => {
+                ColliderShape::Rect(Rect::new(rect.position + location, rect.size))
+            }
+        }
+    }
+
+    /// The smallest rect that contains the shape
+    pub fn bounding_rect(&self) -> Rect {
+        match self {
+            ColliderShape::Circle(circle) => circle.bounding_rect(),
+            ColliderShape::Rect(rect) => *rect,
+        }
+    }
+
+    /// Whether the shapes overlap, shapes that only touch do not overlap
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::collider::ColliderShape;
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let circle = ColliderShape::Circle(Circle::new(Point::new(0.0, 0.0), 5.0));
+    /// let rect = ColliderShape::Rect(Rect::new(Point::new(3.0, -1.0), Point::new(2.0, 2.0)));
+    /// assert!(circle.overlaps(&rect));
+    /// assert!(rect.overlaps(&circle));
+    /// ```
+    pub fn overlaps(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ColliderShape::Circle(circle), ColliderShape::Circle(other)) => {
+                circle.overlaps_circle(other)
+            }
+            (ColliderShape::Circle(circle), ColliderShape::Rect(rect))
+            | (ColliderShape::Rect(rect), ColliderShape::Circle(circle)) => {
+                circle.overlaps_rect(rect)
+            }
+            (ColliderShape::Rect(rect), ColliderShape::Rect(other)) => rect.intersects(other),
+        }
+    }
+}
+
+/// Component that lets the collision system know the entity can collide with other entities. The
+/// shape is placed at the location component of the entity. Two colliders can only collide when
+/// each one is in a layer that the other one's mask includes, by default colliders are in the
+/// default layer and collide with everything.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Collider {
+    pub shape: ColliderShape,
+    pub layers: CollisionLayers,
+    pub mask: CollisionLayers,
+}
+
+impl Collider {
+    pub fn new(shape: ColliderShape) -> Self {
+        Self {
+            shape,
+            layers: CollisionLayers::DEFAULT,
+            mask: CollisionLayers::ALL,
+        }
+    }
+
+    /// A circle collider centered on the location of the entity
+    pub fn circle(radius: f32) -> Self {
+        Self::new(ColliderShape::Circle(Circle::new(
+            Point::new(0.0, 0.0),
+            radius,
+        )))
+    }
+
+    /// A rect collider centered on the location of the entity
+    /// ```
+    /// use bbecs::data_types::collider::{Collider, ColliderShape};
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let collider = Collider::rect(Point::new(10.0, 4.0));
+    /// assert_eq!(
+    ///     collider.shape_at(Point::new(100.0, 100.0)),
+    ///     ColliderShape::Rect(Rect::new(Point::new(95.0, 98.0), Point::new(10.0, 4.0)))
+    /// );
+    /// ```
+    pub fn rect(size: Point) -> Self {
+        Self::new(ColliderShape::Rect(Rect::from_center(
+            Point::new(0.0, 0.0),
+            size,
+        )))
+    }
+
+    /// The shape of the collider when the entity is at the location
+    pub fn shape_at(&self, location: Point) -> ColliderShape {
+        self.shape.translated(location)
+    }
+
+    /// Put the collider in the layers instead of the default layer
+    pub fn with_layers(mut self, layers: CollisionLayers) -> Self {
+        self.layers = layers;
+        self
+    }
+
+    /// Only collide with colliders that are in the layers of the mask
+    pub fn with_mask(mut self, mask: CollisionLayers) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Whether the layers and masks of both colliders allow them to collide, this doesn't check if
+    /// the shapes overlap
+    /// ```
+    /// use bbecs::data_types::collider::Collider;
+    /// use bbecs::data_types::collision_layers::CollisionLayers;
+    /// const PLAYER: CollisionLayers = CollisionLayers::layer(1);
+    /// const PICKUP: CollisionLayers = CollisionLayers::layer(2);
+    /// let player = Collider::circle(10.0).with_layers(PLAYER);
+    /// let pickup = Collider::circle(5.0).with_layers(PICKUP).with_mask(PLAYER);
+    /// let enemy = Collider::circle(10.0);
+    /// assert!(pickup.can_collide_with(&player));
+    /// assert!(!pickup.can_collide_with(&enemy));
+    /// assert!(!enemy.can_collide_with(&pickup));
+    /// ```
+    pub fn can_collide_with(&self, other: &Self) -> bool {
+        self.mask.intersects(other.layers) && other.mask.intersects(self.layers)
+    }
+}
+
+impl Default for Collider {
+    fn default() -> Self {
+        Self::circle(0.0)
+    }
+}
@@ -0,0 +1,38 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// Bitflags for the layers that a collider is in and the layers that it can collide with.
+    /// Games can create their own layers with `CollisionLayers::layer`.
+    /// ```
+    /// use bbecs::data_types::collision_layers::CollisionLayers;
+    /// const PLAYER: CollisionLayers = CollisionLayers::layer(1);
+    /// const ENEMY: CollisionLayers = CollisionLayers::layer(2);
+    /// let mask = PLAYER | ENEMY;
+    /// assert!(mask.intersects(ENEMY));
+    /// assert!(!mask.intersects(CollisionLayers::DEFAULT));
+    /// ```
+    pub struct CollisionLayers: u32 {
+        const NONE = 0;
+        /// The layer that colliders are in when no layer is chosen
+        const DEFAULT = 1;
+        const ALL = u32::MAX;
+    }
+}
+
+impl CollisionLayers {
+    /// The layer with the given index from 0 to 31, where layer 0 is `CollisionLayers::DEFAULT`
+    ///
+    /// # Panics
+    ///
+    /// If the index is 32 or more, since there are only 32 layers
+    pub const fn layer(index: u32) -> Self {
+        assert!(index < 32, "there are only 32 collision layers");
+        Self::from_bits_truncate(1 << index)
+    }
+}
+
+impl Default for CollisionLayers {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,212 @@
+use crate::errors::{BbEcsError, Result};
+
+/// Color that stores red, green, blue, and alpha as f32s between 0.0 and 1.0. This doesn't depend
+/// on any graphics library, but can be converted to and from the ggez color when the `ggez`
+/// feature is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const WHITE: Color = Color::new(1.0, 1.0, 1.0, 1.0);
+    pub const BLACK: Color = Color::new(0.0, 0.0, 0.0, 1.0);
+    pub const RED: Color = Color::new(1.0, 0.0, 0.0, 1.0);
+    pub const GREEN: Color = Color::new(0.0, 1.0, 0.0, 1.0);
+    pub const BLUE: Color = Color::new(0.0, 0.0, 1.0, 1.0);
+    pub const TRANSPARENT: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Create a fully opaque color from red, green, and blue
+    /// ```
+    /// use bbecs::data_types::color::Color;
+    /// let color = Color::from_rgb(0.5, 0.2, 0.8);
+    /// assert_eq!(color, Color::new(0.5, 0.2, 0.8, 1.0));
+    /// ```
+    pub const fn from_rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    /// Create a color from bytes between 0 and 255
+    /// ```
+    /// use bbecs::data_types::color::Color;
+    /// let color = Color::from_rgba_u8(255, 0, 255, 0);
+    /// assert_eq!(color, Color::new(1.0, 0.0, 1.0, 0.0));
+    /// ```
+    pub fn from_rgba_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        )
+    }
+
+    /// Convert the color into bytes between 0 and 255 in the order red, green, blue, alpha
+    /// ```
+    /// use bbecs::data_types::color::Color;
+    /// let color = Color::new(1.0, 0.0, 0.5, 1.0);
+    /// assert_eq!(color.to_rgba_u8(), [255, 0, 128, 255]);
+    /// ```
+    pub fn to_rgba_u8(&self) -> [u8; 4] {
+        let to_byte = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [
+            to_byte(self.r),
+            to_byte(self.g),
+            to_byte(self.b),
+            to_byte(self.a),
+        ]
+    }
+
+    /// Parse a hex string in the form `#RRGGBB` or `#RRGGBBAA`. The leading `#` is optional.
+    /// ```
+    /// use bbecs::data_types::color::Color;
+    /// let color = Color::from_hex("#ff00ff").unwrap();
+    /// assert_eq!(color, Color::new(1.0, 0.0, 1.0, 1.0));
+    ///
+    /// let color = Color::from_hex("00ff0000").unwrap();
+    /// assert_eq!(color, Color::new(0.0, 1.0, 0.0, 0.0));
+    ///
+    /// assert!(Color::from_hex("#fff").is_err());
+    /// assert!(Color::from_hex("##ff0000").is_err());
+    /// assert!(Color::from_hex("#+f0000").is_err());
+    /// assert!(Color::from_hex("#ff00+f").is_err());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let invalid = || BbEcsError::InvalidHexColor {
+            hex: hex.to_owned(),
+        };
+
+        if (digits.len() != 6 && digits.len() != 8)
+            || !digits.chars().all(|digit| digit.is_ascii_hexdigit())
+        {
+            return Err(invalid());
+        }
+
+        let mut bytes = [255_u8; 4];
+        for (index, byte) in bytes.iter_mut().enumerate().take(digits.len() / 2) {
+            *byte =
+                u8::from_str_radix(&digits[index * 2..index * 2 + 2], 16).map_err(|_| invalid())?;
+        }
+
+        Ok(Self::from_rgba_u8(bytes[0], bytes[1], bytes[2], bytes[3]))
+    }
+
+    /// Create a hex string in the form `#RRGGBBAA`
+    /// ```
+    /// use bbecs::data_types::color::Color;
+    /// let color = Color::new(1.0, 0.0, 1.0, 1.0);
+    /// assert_eq!(color.to_hex(), "#ff00ffff");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        let [r, g, b, a] = self.to_rgba_u8();
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
+
+    /// Create a fully opaque color from hue (in degrees), saturation, and lightness. Saturation and
+    /// lightness are between 0.0 and 1.0.
+    /// ```
+    /// use bbecs::data_types::color::Color;
+    /// let color = Color::from_hsl(120.0, 1.0, 0.5);
+    /// assert_eq!(color, Color::GREEN);
+    /// ```
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self::from_hsla(hue, saturation, lightness, 1.0)
+    }
+
+    /// Create a color from hue (in degrees), saturation, lightness, and alpha
+    pub fn from_hsla(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let hue = hue.rem_euclid(360.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let segment = hue / 60.0;
+        let second = chroma * (1.0 - (segment % 2.0 - 1.0).abs());
+        let (r, g, b) = match segment as u32 {
+            0 => (chroma, second, 0.0),
+            1 => (second, chroma, 0.0),
+            2 => (0.0, chroma, second),
+            3 => (0.0, second, chroma),
+            4 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+        let lightness_match = lightness - chroma / 2.0;
+
+        Self::new(
+            r + lightness_match,
+            g + lightness_match,
+            b + lightness_match,
+            alpha,
+        )
+    }
+
+    /// Convert the color into hue (in degrees), saturation, and lightness. Alpha is ignored.
+    /// ```
+    /// use bbecs::data_types::color::Color;
+    /// let (hue, saturation, lightness) = Color::BLUE.to_hsl();
+    /// assert_eq!((hue, saturation, lightness), (240.0, 1.0, 0.5));
+    /// ```
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.0;
+
+        if chroma == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == self.r {
+            60.0 * ((self.g - self.b) / chroma).rem_euclid(6.0)
+        } else if max == self.g {
+            60.0 * ((self.b - self.r) / chroma + 2.0)
+        } else {
+            60.0 * ((self.r - self.g) / chroma + 4.0)
+        };
+
+        (hue, saturation, lightness)
+    }
+
+    /// Linearly interpolate every channel between self and other. A `t` of 0.0 is self, and a
+    /// `t` of 1.0 is other.
+    /// ```
+    /// use bbecs::data_types::color::Color;
+    /// let color = Color::BLACK.lerp(&Color::WHITE, 0.5);
+    /// assert_eq!(color, Color::new(0.5, 0.5, 0.5, 1.0));
+    /// ```
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::new(
+            self.r + (other.r - self.r) * t,
+            self.g + (other.g - self.g) * t,
+            self.b + (other.b - self.b) * t,
+            self.a + (other.a - self.a) * t,
+        )
+    }
+}
+
+/// The default color is opaque white, which draws images and text without tinting them
+impl Default for Color {
+    fn default() -> Self {
+        Self::WHITE
+    }
+}
+
+#[cfg(feature = "ggez")]
+impl From<Color> for ggez::graphics::Color {
+    fn from(color: Color) -> Self {
+        ggez::graphics::Color::new(color.r, color.g, color.b, color.a)
+    }
+}
+
+#[cfg(feature = "ggez")]
+impl From<ggez::graphics::Color> for Color {
+    fn from(color: ggez::graphics::Color) -> Self {
+        Self::new(color.r, color.g, color.b, color.a)
+    }
+}
@@ -0,0 +1,92 @@
+/// What happens to an `EntityRef` when the entity it points at is deleted in `World::update`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OnDespawn {
+    /// Forget the target so that the reference points at nothing
+    #[default]
+    Clear,
+    /// Keep the id of the deleted target and mark the reference as dangling so that systems can
+    /// react to it, for example by deleting the entity that holds the reference
+    Flag,
+}
+
+/// Reference to another entity by its id, for example the target of a homing missile. The world
+/// keeps the reference up to date when the target is deleted instead of letting it silently point
+/// at nothing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EntityRef {
+    target: Option<u32>,
+    on_despawn: OnDespawn,
+    dangling: bool,
+}
+
+impl EntityRef {
+    /// Create a reference to the entity with the given id
+    /// ```
+    /// use bbecs::data_types::entity_ref::{EntityRef, OnDespawn};
+    /// let target = EntityRef::new(5, OnDespawn::Clear);
+    /// assert_eq!(target.get(), Some(5));
+    /// ```
+    pub fn new(entity_id: u32, on_despawn: OnDespawn) -> Self {
+        Self {
+            target: Some(entity_id),
+            on_despawn,
+            dangling: false,
+        }
+    }
+
+    /// The id of the referenced entity, None if nothing is referenced or the target was deleted
+    pub fn get(&self) -> Option<u32> {
+        if self.dangling {
+            None
+        } else {
+            self.target
+        }
+    }
+
+    /// Whether the target was deleted while using `OnDespawn::Flag`
+    pub fn is_dangling(&self) -> bool {
+        self.dangling
+    }
+
+    /// The id of the deleted target if the reference is dangling
+    pub fn dangling_target(&self) -> Option<u32> {
+        if self.dangling {
+            self.target
+        } else {
+            None
+        }
+    }
+
+    /// Point the reference at a different entity, this also clears the dangling flag
+    pub fn set(&mut self, entity_id: u32) {
+        self.target = Some(entity_id);
+        self.dangling = false;
+    }
+
+    /// Stop referencing any entity
+    pub fn clear(&mut self) {
+        self.target = None;
+        self.dangling = false;
+    }
+
+    pub fn on_despawn(&self) -> OnDespawn {
+        self.on_despawn
+    }
+
+    /// Called by the world with the ids of the entities that were just deleted
+    pub(crate) fn entities_despawned(&mut self, entity_ids: &[u32]) {
+        let target = match self.get() {
+            Some(target) => target,
+            None => return,
+        };
+
+        if !entity_ids.contains(&target) {
+            return;
+        }
+
+        match self.on_despawn {
+            OnDespawn::Clear => self.target = None,
+            OnDespawn::Flag => self.dangling = true,
+        }
+    }
+}
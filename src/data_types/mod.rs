@@ -1 +1,12 @@
+pub mod boundary;
+pub mod circle;
+pub mod collider;
+pub mod collision_layers;
+pub mod color;
+pub mod entity_ref;
 pub mod point;
+pub mod rect;
+pub mod rng;
+pub mod spatial_hash;
+pub mod timer;
+pub mod transform;
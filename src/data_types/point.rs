@@ -1,5 +1,9 @@
 use std::iter::Sum;
-use std::ops::{AddAssign, Div, Sub};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::circle::Circle;
+use super::rect::Rect;
+use super::rng::Rng;
 
 /// Point that stores a f32 x and y with methods for manipulating the point. Uses Vector math
 /// for the methods
@@ -107,6 +111,191 @@ impl Point {
             self.y = max;
         }
     }
+
+    /// The dot product of the two points
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let a = Point::new(1.0, 2.0);
+    /// let b = Point::new(3.0, 4.0);
+    /// assert_eq!(a.dot(&b), 11.0);
+    /// ```
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The 2D cross product, which is the z component of the 3D cross product. It is positive when
+    /// other is clockwise from self in screen coordinates (y pointing down).
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let right = Point::new(1.0, 0.0);
+    /// let down = Point::new(0.0, 1.0);
+    /// assert_eq!(right.cross(&down), 1.0);
+    /// assert_eq!(down.cross(&right), -1.0);
+    /// ```
+    pub fn cross(&self, other: &Self) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Create a point pointing in the direction of the angle (in radians) with the given length
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let velocity = Point::from_angle(std::f32::consts::FRAC_PI_2, 5.0);
+    /// assert!(velocity.distance_to(&Point::new(0.0, 5.0)) < 0.0001);
+    /// ```
+    pub fn from_angle(radians: f32, length: f32) -> Self {
+        Self::new(radians.cos() * length, radians.sin() * length)
+    }
+
+    /// Rotate the point around the origin by the angle in radians, mutating itself
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let mut velocity = Point::new(1.0, 0.0);
+    /// velocity.rotate(std::f32::consts::PI);
+    /// assert!(velocity.distance_to(&Point::new(-1.0, 0.0)) < 0.0001);
+    /// ```
+    pub fn rotate(&mut self, radians: f32) {
+        let (sin, cos) = radians.sin_cos();
+        let x = self.x * cos - self.y * sin;
+        self.y = self.x * sin + self.y * cos;
+        self.x = x;
+    }
+
+    /// Create a new point that is `t` of the way from self to other. A `t` of 0.0 is self and a
+    /// `t` of 1.0 is other.
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let start = Point::new(0.0, 0.0);
+    /// let end = Point::new(10.0, 20.0);
+    /// assert_eq!(start.lerp(&end, 0.5), Point::new(5.0, 10.0));
+    /// ```
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+        )
+    }
+
+    /// The signed angle in radians to rotate self so that it points the same way as other, between
+    /// -PI and PI
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let right = Point::new(1.0, 0.0);
+    /// let down = Point::new(0.0, 1.0);
+    /// assert_eq!(right.angle_between(&down), std::f32::consts::FRAC_PI_2);
+    /// assert_eq!(down.angle_between(&right), -std::f32::consts::FRAC_PI_2);
+    /// ```
+    pub fn angle_between(&self, other: &Self) -> f32 {
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// Create a new point that is self bounced off of a surface with the given normal. The normal
+    /// should be normalized.
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let velocity = Point::new(1.0, 1.0);
+    /// let floor_normal = Point::new(0.0, -1.0);
+    /// assert_eq!(velocity.reflect(&floor_normal), Point::new(1.0, -1.0));
+    /// ```
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * (2.0 * self.dot(normal))
+    }
+
+    /// Create a new point that is the part of self that points in the direction of other
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let velocity = Point::new(3.0, 4.0);
+    /// let ground = Point::new(10.0, 0.0);
+    /// assert_eq!(velocity.project_onto(&ground), Point::new(3.0, 0.0));
+    /// ```
+    pub fn project_onto(&self, other: &Self) -> Self {
+        let length_squared = other.dot(other);
+        if length_squared == 0.0 {
+            return Self::default();
+        }
+
+        *other * (self.dot(other) / length_squared)
+    }
+
+    /// Shorten the point so that the length is no longer than max, mutating itself. Points that
+    /// are already short enough are left alone.
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let mut velocity = Point::new(30.0, 40.0);
+    /// velocity.clamp_length(5.0);
+    /// assert_eq!(velocity, Point::new(3.0, 4.0));
+    ///
+    /// velocity.clamp_length(10.0);
+    /// assert_eq!(velocity, Point::new(3.0, 4.0));
+    /// ```
+    pub fn clamp_length(&mut self, max: f32) {
+        let length = self.length();
+        if length > max && length != 0.0 {
+            self.multiply_scalar(max / length);
+        }
+    }
+
+    /// The distance to the other point squared, which is faster than `distance_to` when only
+    /// comparing distances
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let location = Point::new(0.0, 0.0);
+    /// assert_eq!(location.distance_squared(&Point::new(3.0, 4.0)), 25.0);
+    /// ```
+    pub fn distance_squared(&self, other: &Self) -> f32 {
+        let distance = *other - *self;
+        distance.dot(&distance)
+    }
+
+    /// The length of the point squared, which is faster than `length` when only comparing lengths
+    pub fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    /// A random point inside of the circle, spread evenly over its area
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rng::Rng;
+    /// let mut rng = Rng::new(7);
+    /// let spawn_area = Circle::new(Point::new(100.0, 100.0), 20.0);
+    /// let location = Point::random_in_circle(&mut rng, &spawn_area);
+    /// assert!(spawn_area.contains_point(&location));
+    /// ```
+    pub fn random_in_circle(rng: &mut Rng, circle: &Circle) -> Self {
+        let distance = circle.radius * rng.next_f32().sqrt();
+        circle.center + Self::from_angle(rng.angle(), distance)
+    }
+
+    /// A random point on the edge of the circle
+    /// ```
+    /// use bbecs::data_types::circle::Circle;
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rng::Rng;
+    /// let mut rng = Rng::new(7);
+    /// let ring = Circle::new(Point::new(0.0, 0.0), 50.0);
+    /// let location = Point::random_on_circle(&mut rng, &ring);
+    /// assert!((location.length() - 50.0).abs() < 0.001);
+    /// ```
+    pub fn random_on_circle(rng: &mut Rng, circle: &Circle) -> Self {
+        circle.center + Self::from_angle(rng.angle(), circle.radius)
+    }
+
+    /// A random point inside of the rect
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// use bbecs::data_types::rng::Rng;
+    /// let mut rng = Rng::new(7);
+    /// let arena = Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0));
+    /// let location = Point::random_in_rect(&mut rng, &arena);
+    /// assert!(arena.contains_point(&location));
+    /// ```
+    pub fn random_in_rect(rng: &mut Rng, rect: &Rect) -> Self {
+        Self::new(
+            rng.range(rect.left(), rect.right()),
+            rng.range(rect.top(), rect.bottom()),
+        )
+    }
 }
 
 impl Default for Point {
@@ -115,6 +304,21 @@ impl Default for Point {
     }
 }
 
+impl Add for Point {
+    type Output = Self;
+
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// assert_eq!(Point::new(1.0, 2.0) + Point::new(3.0, 4.0), Point::new(4.0, 6.0));
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
 impl AddAssign for Point {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
@@ -133,6 +337,84 @@ impl Sub for Point {
     }
 }
 
+impl SubAssign for Point {
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let mut location = Point::new(5.0, 5.0);
+    /// location -= Point::new(1.0, 2.0);
+    /// assert_eq!(location, Point::new(4.0, 3.0));
+    /// ```
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl Mul<f32> for Point {
+    type Output = Self;
+
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// assert_eq!(Point::new(1.0, 2.0) * 10.0, Point::new(10.0, 20.0));
+    /// ```
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl MulAssign<f32> for Point {
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// let mut velocity = Point::new(1.0, 2.0);
+    /// velocity *= 0.5;
+    /// assert_eq!(velocity, Point::new(0.5, 1.0));
+    /// ```
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl Div<f32> for Point {
+    type Output = Self;
+
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// assert_eq!(Point::new(10.0, 20.0) / 10.0, Point::new(1.0, 2.0));
+    /// ```
+    fn div(self, rhs: f32) -> Self {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl DivAssign<f32> for Point {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// assert_eq!(-Point::new(1.0, -2.0), Point::new(-1.0, 2.0));
+    /// ```
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
 impl Div<usize> for Point {
     type Output = Self;
 
@@ -147,8 +429,78 @@ impl Div<usize> for Point {
 impl Sum<Point> for Point {
     fn sum<I: Iterator<Item = Point>>(points: I) -> Self {
         points.fold(Self::new(0.0, 0.0), |mut summed_point, point| {
-            summed_point.add(&point);
+            summed_point += point;
             summed_point
         })
     }
 }
+
+impl From<[f32; 2]> for Point {
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// assert_eq!(Point::from([1.0, 2.0]), Point::new(1.0, 2.0));
+    /// ```
+    fn from([x, y]: [f32; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<(f32, f32)> for Point {
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// assert_eq!(Point::from((1.0, 2.0)), Point::new(1.0, 2.0));
+    /// ```
+    fn from((x, y): (f32, f32)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point> for [f32; 2] {
+    fn from(point: Point) -> Self {
+        point.to_array()
+    }
+}
+
+/// Allows points to be passed directly to anything that takes a mint point, like the ggez draw
+/// param `dest`.
+/// ```
+/// use bbecs::data_types::point::Point;
+/// let location: mint::Point2<f32> = Point::new(1.0, 2.0).into();
+/// assert_eq!(location, mint::Point2 { x: 1.0, y: 2.0 });
+/// ```
+impl From<Point> for mint::Point2<f32> {
+    fn from(point: Point) -> Self {
+        Self {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<mint::Point2<f32>> for Point {
+    fn from(point: mint::Point2<f32>) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+/// Allows points to be passed directly to anything that takes a mint vector, like the ggez draw
+/// param `scale`.
+/// ```
+/// use bbecs::data_types::point::Point;
+/// let scale: mint::Vector2<f32> = Point::new(2.0, 2.0).into();
+/// assert_eq!(scale, mint::Vector2 { x: 2.0, y: 2.0 });
+/// ```
+impl From<Point> for mint::Vector2<f32> {
+    fn from(point: Point) -> Self {
+        Self {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<mint::Vector2<f32>> for Point {
+    fn from(vector: mint::Vector2<f32>) -> Self {
+        Self::new(vector.x, vector.y)
+    }
+}
@@ -0,0 +1,163 @@
+use super::point::Point;
+
+/// Axis aligned rectangle with the position being the top left corner, useful for screen bounds and
+/// hitboxes. The size is expected to be positive.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub position: Point,
+    pub size: Point,
+}
+
+impl Rect {
+    pub fn new(position: Point, size: Point) -> Self {
+        Self { position, size }
+    }
+
+    /// Create a rect that is centered on the given point
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let rect = Rect::from_center(Point::new(10.0, 10.0), Point::new(4.0, 2.0));
+    /// assert_eq!(rect.position, Point::new(8.0, 9.0));
+    /// ```
+    pub fn from_center(center: Point, size: Point) -> Self {
+        Self::new(center - size / 2.0, size)
+    }
+
+    pub fn left(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn right(&self) -> f32 {
+        self.position.x + self.size.x
+    }
+
+    pub fn top(&self) -> f32 {
+        self.position.y
+    }
+
+    pub fn bottom(&self) -> f32 {
+        self.position.y + self.size.y
+    }
+
+    pub fn width(&self) -> f32 {
+        self.size.x
+    }
+
+    pub fn height(&self) -> f32 {
+        self.size.y
+    }
+
+    /// The point in the middle of the rect
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 20.0));
+    /// assert_eq!(rect.center(), Point::new(5.0, 10.0));
+    /// ```
+    pub fn center(&self) -> Point {
+        self.position + self.size / 2.0
+    }
+
+    /// Whether the point is inside of the rect, points on the left and top edges are inside while
+    /// points on the right and bottom edges are not so that rects that share an edge don't both
+    /// contain the same point
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    /// assert!(rect.contains_point(&Point::new(0.0, 5.0)));
+    /// assert!(!rect.contains_point(&Point::new(10.0, 5.0)));
+    /// ```
+    pub fn contains_point(&self, point: &Point) -> bool {
+        point.x >= self.left()
+            && point.x < self.right()
+            && point.y >= self.top()
+            && point.y < self.bottom()
+    }
+
+    /// Whether the rects overlap, rects that only touch on an edge do not overlap
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    /// assert!(rect.intersects(&Rect::new(Point::new(5.0, 5.0), Point::new(10.0, 10.0))));
+    /// assert!(!rect.intersects(&Rect::new(Point::new(10.0, 0.0), Point::new(10.0, 10.0))));
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.left() < other.right()
+            && other.left() < self.right()
+            && self.top() < other.bottom()
+            && other.top() < self.bottom()
+    }
+
+    /// The rect where both rects overlap, None if they don't overlap
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    /// let other = Rect::new(Point::new(5.0, 5.0), Point::new(10.0, 10.0));
+    /// let overlap = Rect::new(Point::new(5.0, 5.0), Point::new(5.0, 5.0));
+    /// assert_eq!(rect.intersection(&other), Some(overlap));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let top_left = Point::new(self.left().max(other.left()), self.top().max(other.top()));
+        let bottom_right = Point::new(
+            self.right().min(other.right()),
+            self.bottom().min(other.bottom()),
+        );
+
+        Some(Self::new(top_left, bottom_right - top_left))
+    }
+
+    /// The smallest rect that contains both rects
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    /// let other = Rect::new(Point::new(20.0, 5.0), Point::new(10.0, 10.0));
+    /// let union = Rect::new(Point::new(0.0, 0.0), Point::new(30.0, 15.0));
+    /// assert_eq!(rect.union(&other), union);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let top_left = Point::new(self.left().min(other.left()), self.top().min(other.top()));
+        let bottom_right = Point::new(
+            self.right().max(other.right()),
+            self.bottom().max(other.bottom()),
+        );
+
+        Self::new(top_left, bottom_right - top_left)
+    }
+
+    /// The closest point inside of the rect to the given point. A rect with a negative size is
+    /// treated as the area between its edges.
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    /// assert_eq!(rect.closest_point(&Point::new(15.0, 5.0)), Point::new(10.0, 5.0));
+    /// ```
+    pub fn closest_point(&self, point: &Point) -> Point {
+        let (left, right) = (self.left().min(self.right()), self.left().max(self.right()));
+        let (top, bottom) = (self.top().min(self.bottom()), self.top().max(self.bottom()));
+        Point::new(point.x.max(left).min(right), point.y.max(top).min(bottom))
+    }
+}
+
+#[cfg(feature = "ggez")]
+impl From<Rect> for ggez::graphics::Rect {
+    fn from(rect: Rect) -> Self {
+        ggez::graphics::Rect::new(rect.position.x, rect.position.y, rect.size.x, rect.size.y)
+    }
+}
+
+#[cfg(feature = "ggez")]
+impl From<ggez::graphics::Rect> for Rect {
+    fn from(rect: ggez::graphics::Rect) -> Self {
+        Self::new(Point::new(rect.x, rect.y), Point::new(rect.w, rect.h))
+    }
+}
@@ -0,0 +1,101 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Seedable random number generator. Every world owns one, see `World::seed_rng` and
+/// `World::rng_mut`, so that every system draws from the same sequence. Creating it with the same
+/// seed gives the same numbers on every platform, which makes whole game sessions reproducible.
+/// Uses the SplitMix64 algorithm, which is fast and good enough for games but must not be used for
+/// anything security related.
+/// ```
+/// use bbecs::data_types::rng::Rng;
+/// let mut rng = Rng::new(42);
+/// let mut same_seed = Rng::new(42);
+/// assert_eq!(rng.next_u64(), same_seed.next_u64());
+///
+/// let roll = rng.range_u32(1, 7);
+/// assert!((1..7).contains(&roll));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Rng {
+    seed: u64,
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, state: seed }
+    }
+
+    /// Create a generator seeded from the current time for when the session doesn't need to be
+    /// reproduced. The seed can still be read with `Rng::seed` to replay the session later.
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+        Self::new(nanos)
+    }
+
+    /// The seed that the generator was created with
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Start the sequence over from the seed
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.state = seed;
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut result = self.state;
+        result = (result ^ (result >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        result = (result ^ (result >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        result ^ (result >> 31)
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// A random number from 0.0 up to but not including 1.0
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1_u64 << 24) as f32
+    }
+
+    /// A random number from min up to but not including max
+    /// ```
+    /// use bbecs::data_types::rng::Rng;
+    /// let mut rng = Rng::new(0);
+    /// let speed = rng.range(2.0, 5.0);
+    /// assert!((2.0..5.0).contains(&speed));
+    /// ```
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    /// A random whole number from min up to but not including max, returns min if the range is
+    /// empty
+    pub fn range_u32(&mut self, min: u32, max: u32) -> u32 {
+        if max <= min {
+            return min;
+        }
+
+        min + (self.next_u64() % u64::from(max - min)) as u32
+    }
+
+    /// True with the given chance between 0.0 and 1.0
+    /// ```
+    /// use bbecs::data_types::rng::Rng;
+    /// let mut rng = Rng::new(0);
+    /// assert!(rng.chance(1.0));
+    /// assert!(!rng.chance(0.0));
+    /// ```
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+
+    /// A random angle in radians from 0.0 up to a full turn
+    pub fn angle(&mut self) -> f32 {
+        self.range(0.0, std::f32::consts::TAU)
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::collision_layers::CollisionLayers;
+use super::point::Point;
+use super::rect::Rect;
+
+/// Index of entity locations split into a grid of square cells so that finding the entities near a
+/// point only has to look at the cells around it instead of every entity. The cell size should be
+/// about the size of the radius that is usually queried. Locations far outside of the range of an
+/// `i32` number of cells share the cells on the edge of that range. Store it as a resource and keep
+/// it up to date with `World::update_spatial_hash`, or insert and remove entities by hand when they
+/// move. Each entity is in collision layers so that the masked queries only find the entities that
+/// a collider with that mask could collide with.
+/// ```
+/// use bbecs::data_types::point::Point;
+/// use bbecs::data_types::spatial_hash::SpatialHash;
+/// let mut spatial_hash = SpatialHash::new(10.0);
+/// spatial_hash.insert(0, Point::new(5.0, 5.0));
+/// spatial_hash.insert(1, Point::new(8.0, 5.0));
+/// spatial_hash.insert(2, Point::new(50.0, 50.0));
+///
+/// let mut nearby = spatial_hash.query_radius(&Point::new(4.0, 5.0), 5.0);
+/// nearby.sort();
+/// assert_eq!(nearby, vec![0, 1]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialHash {
+    cell_size: f32,
+    /// Ordered so that queries return the entities in the same order on every run
+    cells: BTreeMap<(i32, i32), Vec<u32>>,
+    entries: HashMap<u32, (Point, CollisionLayers)>,
+}
+
+impl SpatialHash {
+    /// Create an empty spatial hash
+    ///
+    /// # Panics
+    ///
+    /// If the cell size isn't a finite number larger than 0.0
+    pub fn new(cell_size: f32) -> Self {
+        assert!(
+            cell_size > 0.0 && cell_size.is_finite(),
+            "the cell size of a spatial hash must be finite and larger than 0.0, got {}",
+            cell_size
+        );
+        Self {
+            cell_size,
+            cells: BTreeMap::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Insert the entity at the location in the default collision layer, an entity that is already
+    /// in the spatial hash is moved to the new location
+    pub fn insert(&mut self, entity_id: u32, location: Point) {
+        self.insert_in_layers(entity_id, location, CollisionLayers::DEFAULT);
+    }
+
+    /// Insert the entity at the location in the collision layers, usually the layers of the
+    /// entity's collider. A location that isn't finite can't be found by any query, so the entity
+    /// is removed instead.
+    pub fn insert_in_layers(&mut self, entity_id: u32, location: Point, layers: CollisionLayers) {
+        if !location.x.is_finite() || !location.y.is_finite() {
+            self.remove(entity_id);
+            return;
+        }
+
+        let cell = self.cell_of(&location);
+
+        if let Some((old_location, _)) = self.entries.insert(entity_id, (location, layers)) {
+            let old_cell = self.cell_of(&old_location);
+            if old_cell == cell {
+                return;
+            }
+            self.remove_from_cell(old_cell, entity_id);
+        }
+
+        self.cells.entry(cell).or_default().push(entity_id);
+    }
+
+    /// Remove the entity, returning its location if it was in the spatial hash
+    pub fn remove(&mut self, entity_id: u32) -> Option<Point> {
+        let (location, _) = self.entries.remove(&entity_id)?;
+        self.remove_from_cell(self.cell_of(&location), entity_id);
+        Some(location)
+    }
+
+    /// The location that the entity was inserted at
+    pub fn location(&self, entity_id: u32) -> Option<Point> {
+        self.entries.get(&entity_id).map(|(location, _)| *location)
+    }
+
+    /// The collision layers that the entity was inserted in
+    pub fn layers(&self, entity_id: u32) -> Option<CollisionLayers> {
+        self.entries.get(&entity_id).map(|(_, layers)| *layers)
+    }
+
+    pub fn contains(&self, entity_id: u32) -> bool {
+        self.entries.contains_key(&entity_id)
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The ids of the entities that are within the radius of the point, including entities that
+    /// are exactly on the edge
+    pub fn query_radius(&self, point: &Point, radius: f32) -> Vec<u32> {
+        self.query_radius_masked(point, radius, CollisionLayers::ALL)
+    }
+
+    /// The ids of the entities in the layers of the mask that are within the radius of the point
+    /// ```
+    /// use bbecs::data_types::collision_layers::CollisionLayers;
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::spatial_hash::SpatialHash;
+    /// const ENEMY: CollisionLayers = CollisionLayers::layer(2);
+    /// let mut spatial_hash = SpatialHash::new(10.0);
+    /// spatial_hash.insert(0, Point::new(5.0, 5.0));
+    /// spatial_hash.insert_in_layers(1, Point::new(6.0, 5.0), ENEMY);
+    ///
+    /// let enemies = spatial_hash.query_radius_masked(&Point::new(5.0, 5.0), 5.0, ENEMY);
+    /// assert_eq!(enemies, vec![1]);
+    /// ```
+    pub fn query_radius_masked(
+        &self,
+        point: &Point,
+        radius: f32,
+        mask: CollisionLayers,
+    ) -> Vec<u32> {
+        let area = Rect::from_center(*point, Point::new(radius, radius) * 2.0);
+        let radius_squared = radius * radius;
+
+        self.entities_in_cells(&area, mask)
+            .filter(|(_, location)| location.distance_squared(point) <= radius_squared)
+            .map(|(entity_id, _)| entity_id)
+            .collect()
+    }
+
+    /// The ids of the entities that are inside of the rect, using the same edge rules as
+    /// `Rect::contains_point`
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::rect::Rect;
+    /// use bbecs::data_types::spatial_hash::SpatialHash;
+    /// let mut spatial_hash = SpatialHash::new(10.0);
+    /// spatial_hash.insert(0, Point::new(5.0, 5.0));
+    /// spatial_hash.insert(1, Point::new(25.0, 5.0));
+    ///
+    /// let screen = Rect::new(Point::new(0.0, 0.0), Point::new(20.0, 20.0));
+    /// assert_eq!(spatial_hash.query_rect(&screen), vec![0]);
+    /// ```
+    pub fn query_rect(&self, rect: &Rect) -> Vec<u32> {
+        self.query_rect_masked(rect, CollisionLayers::ALL)
+    }
+
+    /// The ids of the entities in the layers of the mask that are inside of the rect
+    pub fn query_rect_masked(&self, rect: &Rect, mask: CollisionLayers) -> Vec<u32> {
+        self.entities_in_cells(rect, mask)
+            .filter(|(_, location)| rect.contains_point(location))
+            .map(|(entity_id, _)| entity_id)
+            .collect()
+    }
+
+    /// Every entity in the layers of the mask in the cells that the area touches along with its
+    /// location, in the order of the cells. When the area touches more cells than there are
+    /// occupied cells, which happens with huge areas, the occupied cells are checked instead so that
+    /// the work stays bounded by the number of entities.
+    fn entities_in_cells<'a>(
+        &'a self,
+        area: &Rect,
+        mask: CollisionLayers,
+    ) -> impl Iterator<Item = (u32, Point)> + 'a {
+        let (left, top) = self.cell_of(&area.position);
+        let (right, bottom) = self.cell_of(&Point::new(area.right(), area.bottom()));
+        let width = i64::from(right) - i64::from(left) + 1;
+        let height = i64::from(bottom) - i64::from(top) + 1;
+
+        let cells: Vec<&Vec<u32>> = if width.saturating_mul(height) > self.cells.len() as i64 {
+            self.cells
+                .iter()
+                .filter(|((x, y), _)| (left..=right).contains(x) && (top..=bottom).contains(y))
+                .map(|(_, entity_ids)| entity_ids)
+                .collect()
+        } else {
+            (left..=right)
+                .flat_map(|x| (top..=bottom).map(move |y| (x, y)))
+                .filter_map(|cell| self.cells.get(&cell))
+                .collect()
+        };
+
+        cells
+            .into_iter()
+            .flatten()
+            .map(move |entity_id| (*entity_id, self.entries[entity_id]))
+            .filter(move |(_, (_, layers))| mask.intersects(*layers))
+            .map(|(entity_id, (location, _))| (entity_id, location))
+    }
+
+    fn cell_of(&self, location: &Point) -> (i32, i32) {
+        (
+            (location.x / self.cell_size).floor() as i32,
+            (location.y / self.cell_size).floor() as i32,
+        )
+    }
+
+    fn remove_from_cell(&mut self, cell: (i32, i32), entity_id: u32) {
+        if let Some(entity_ids) = self.cells.get_mut(&cell) {
+            entity_ids.retain(|id| *id != entity_id);
+            if entity_ids.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+}
+
+impl Default for SpatialHash {
+    fn default() -> Self {
+        Self::new(64.0)
+    }
+}
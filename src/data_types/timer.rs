@@ -0,0 +1,146 @@
+/// Whether a timer stops after finishing or starts over
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TimerMode {
+    #[default]
+    Once,
+    Repeating,
+}
+
+/// Timer for cooldowns, spawn intervals, invulnerability frames and anything else that needs to
+/// happen after some amount of time. Timers stored as components are advanced by
+/// `World::tick_timers`, and `just_finished` is only true on the tick that the timer completed.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Timer {
+    duration: f32,
+    elapsed: f32,
+    mode: TimerMode,
+    finished: bool,
+    just_finished: bool,
+}
+
+impl Timer {
+    pub fn new(duration: f32, mode: TimerMode) -> Self {
+        Self {
+            duration,
+            elapsed: 0.0,
+            mode,
+            finished: false,
+            just_finished: false,
+        }
+    }
+
+    /// Create a timer that finishes once and then stops
+    /// ```
+    /// use bbecs::data_types::timer::Timer;
+    /// let mut timer = Timer::once(1.0);
+    /// timer.tick(0.6);
+    /// assert!(!timer.just_finished());
+    /// timer.tick(0.6);
+    /// assert!(timer.just_finished());
+    /// timer.tick(0.6);
+    /// assert!(!timer.just_finished());
+    /// assert!(timer.finished());
+    /// ```
+    pub fn once(duration: f32) -> Self {
+        Self::new(duration, TimerMode::Once)
+    }
+
+    /// Create a timer that starts over every time it finishes
+    /// ```
+    /// use bbecs::data_types::timer::Timer;
+    /// let mut timer = Timer::repeating(1.0);
+    /// timer.tick(1.5);
+    /// assert!(timer.just_finished());
+    /// assert_eq!(timer.elapsed(), 0.5);
+    /// timer.tick(1.0);
+    /// assert!(timer.just_finished());
+    /// ```
+    pub fn repeating(duration: f32) -> Self {
+        Self::new(duration, TimerMode::Repeating)
+    }
+
+    /// Advance the timer by the delta time
+    pub fn tick(&mut self, delta_time: f32) {
+        if self.finished {
+            self.just_finished = false;
+            return;
+        }
+
+        self.elapsed += delta_time;
+        self.just_finished = self.elapsed >= self.duration;
+
+        if !self.just_finished {
+            return;
+        }
+
+        match self.mode {
+            TimerMode::Once => {
+                self.elapsed = self.duration;
+                self.finished = true;
+            }
+            TimerMode::Repeating if self.duration > 0.0 => self.elapsed %= self.duration,
+            TimerMode::Repeating => self.elapsed = 0.0,
+        }
+    }
+
+    /// True only for the tick that the timer finished on
+    pub fn just_finished(&self) -> bool {
+        self.just_finished
+    }
+
+    /// True once a `TimerMode::Once` timer has finished, repeating timers never stay finished
+    pub fn finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Start the timer over from the beginning
+    /// ```
+    /// use bbecs::data_types::timer::Timer;
+    /// let mut timer = Timer::once(1.0);
+    /// timer.tick(1.0);
+    /// timer.reset();
+    /// assert!(!timer.finished());
+    /// assert_eq!(timer.elapsed(), 0.0);
+    /// ```
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+        self.finished = false;
+        self.just_finished = false;
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    pub fn set_duration(&mut self, duration: f32) {
+        self.duration = duration;
+    }
+
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+
+    /// The time left before the timer finishes
+    pub fn remaining(&self) -> f32 {
+        (self.duration - self.elapsed).max(0.0)
+    }
+
+    /// How far through the timer is between 0.0 and 1.0
+    /// ```
+    /// use bbecs::data_types::timer::Timer;
+    /// let mut timer = Timer::once(2.0);
+    /// timer.tick(0.5);
+    /// assert_eq!(timer.percent(), 0.25);
+    /// ```
+    pub fn percent(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).min(1.0)
+        }
+    }
+}
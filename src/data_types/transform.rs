@@ -0,0 +1,152 @@
+use std::ops::Mul;
+
+use super::point::Point;
+
+/// Position, rotation (in radians) and scale of an entity in one component. Points are scaled
+/// first, then rotated and then moved by the position. Composing and inverting transforms is exact
+/// as long as the scale is the same on both axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub position: Point,
+    pub rotation: f32,
+    pub scale: Point,
+}
+
+impl Transform {
+    pub fn new(position: Point, rotation: f32, scale: Point) -> Self {
+        Self {
+            position,
+            rotation,
+            scale,
+        }
+    }
+
+    /// A transform that leaves points where they are
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::transform::Transform;
+    /// let point = Point::new(3.0, 4.0);
+    /// assert_eq!(Transform::identity().transform_point(&point), point);
+    /// ```
+    pub fn identity() -> Self {
+        Self::new(Point::new(0.0, 0.0), 0.0, Point::new(1.0, 1.0))
+    }
+
+    /// Create a transform that only moves points
+    pub fn from_position(position: Point) -> Self {
+        Self {
+            position,
+            ..Self::identity()
+        }
+    }
+
+    /// Move a point from the local space of the transform into the space the transform is in
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::transform::Transform;
+    /// let ship = Transform::new(Point::new(10.0, 10.0), std::f32::consts::FRAC_PI_2, Point::new(2.0, 2.0));
+    /// let gun = ship.transform_point(&Point::new(5.0, 0.0));
+    /// assert!(gun.distance_to(&Point::new(10.0, 20.0)) < 0.0001);
+    /// ```
+    pub fn transform_point(&self, point: &Point) -> Point {
+        let mut transformed = Point::new(point.x * self.scale.x, point.y * self.scale.y);
+        transformed.rotate(self.rotation);
+        transformed + self.position
+    }
+
+    /// Move a point from the space the transform is in into the local space of the transform, this
+    /// is exact even when the scale is different on each axis
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::transform::Transform;
+    /// let ship = Transform::new(Point::new(10.0, 10.0), std::f32::consts::FRAC_PI_2, Point::new(2.0, 2.0));
+    /// let local = ship.inverse_transform_point(&Point::new(10.0, 20.0));
+    /// assert!(local.distance_to(&Point::new(5.0, 0.0)) < 0.0001);
+    /// ```
+    pub fn inverse_transform_point(&self, point: &Point) -> Point {
+        let mut transformed = *point - self.position;
+        transformed.rotate(-self.rotation);
+        Point::new(transformed.x / self.scale.x, transformed.y / self.scale.y)
+    }
+
+    /// The transform that undoes this one
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::transform::Transform;
+    /// let ship = Transform::new(Point::new(10.0, 10.0), 1.0, Point::new(2.0, 2.0));
+    /// let point = Point::new(3.0, 4.0);
+    /// let round_trip = ship.inverse().transform_point(&ship.transform_point(&point));
+    /// assert!(round_trip.distance_to(&point) < 0.0001);
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let scale = Point::new(1.0 / self.scale.x, 1.0 / self.scale.y);
+        let mut position = -self.position;
+        position.rotate(-self.rotation);
+
+        Self::new(
+            Point::new(position.x * scale.x, position.y * scale.y),
+            -self.rotation,
+            scale,
+        )
+    }
+
+    /// Move the transform by the offset
+    pub fn translate(&mut self, offset: Point) {
+        self.position += offset;
+    }
+
+    /// Rotate the transform around its position by the angle in radians
+    pub fn rotate(&mut self, radians: f32) {
+        self.rotation += radians;
+    }
+
+    /// The direction the transform is facing as a unit length point
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::transform::Transform;
+    /// let transform = Transform::new(Point::new(5.0, 5.0), 0.0, Point::new(1.0, 1.0));
+    /// assert_eq!(transform.forward(), Point::new(1.0, 0.0));
+    /// ```
+    pub fn forward(&self) -> Point {
+        Point::from_angle(self.rotation, 1.0)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Mul for Transform {
+    type Output = Self;
+
+    /// Compose a parent transform with a child transform, the result places the child in the space
+    /// that the parent is in
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::transform::Transform;
+    /// let ship = Transform::new(Point::new(10.0, 10.0), 0.0, Point::new(2.0, 2.0));
+    /// let turret = Transform::from_position(Point::new(5.0, 0.0));
+    /// let world_turret = ship * turret;
+    /// assert_eq!(world_turret.position, Point::new(20.0, 10.0));
+    /// assert_eq!(world_turret.scale, Point::new(2.0, 2.0));
+    /// ```
+    fn mul(self, child: Self) -> Self {
+        Self::new(
+            self.transform_point(&child.position),
+            self.rotation + child.rotation,
+            Point::new(self.scale.x * child.scale.x, self.scale.y * child.scale.y),
+        )
+    }
+}
+
+#[cfg(feature = "ggez")]
+impl From<Transform> for ggez::graphics::DrawParam {
+    fn from(transform: Transform) -> Self {
+        ggez::graphics::DrawParam::new()
+            .dest(transform.position)
+            .rotation(transform.rotation)
+            .scale(transform.scale)
+    }
+}
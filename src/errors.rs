@@ -1,25 +1,84 @@
 use thiserror::Error;
 
+pub type Result<T> = std::result::Result<T, BbEcsError>;
+
 #[derive(Debug, Error)]
 pub enum BbEcsError {
-    #[error("attempted to cast component from `{0}`")]
-    CastingComponents(&'static str),
-    #[error("attempted to cast resource from to `{0}`")]
-    CastingResource(&'static str),
-    #[error("you need to register before inserting components")]
-    NeedToRegister,
-    #[error("component with name `{0}` not found")]
-    ComponentNotFound(String),
-    #[error("resource with names `{0} not found")]
-    ResourceNotFound(String),
-    #[error("already registered component with name `{0}`")]
-    ComponentAlreadyRegistered(String),
-    #[error("tried to insert into the bitmap before registering")]
-    BitMapInsertBeforeRegister,
-    #[error("BitMap component `{0}` not found")]
-    BitMapComponentNotFound(String),
     #[error(
-        "You tried to access a vector with an index that is greater than the length of the vector"
+        "attempted to cast component{} of type `{found}` to `{expected}`",
+        named(.name)
+    )]
+    CastingComponents {
+        name: Option<String>,
+        expected: &'static str,
+        found: &'static str,
+    },
+    #[error(
+        "attempted to cast resource{} of type `{found}` to `{expected}`",
+        named(.name)
     )]
-    OutOfRangeInVector,
+    CastingResource {
+        name: Option<String>,
+        expected: &'static str,
+        found: &'static str,
+    },
+    #[error("you need to register component `{name}` before inserting it")]
+    NeedToRegister { name: String },
+    #[error("component with name `{name}` not found")]
+    ComponentNotFound { name: String },
+    #[error("resource with name `{name}` not found")]
+    ResourceNotFound { name: String },
+    #[error("already registered component with name `{name}`")]
+    ComponentAlreadyRegistered { name: String },
+    #[error("tried to insert `{name}` into the bitmap before registering")]
+    BitMapInsertBeforeRegister { name: String },
+    #[error("BitMap component `{name}` not found")]
+    BitMapComponentNotFound { name: String },
+    #[error("component `{name}` is managed by the world and cannot be unregistered")]
+    CannotUnregisterBuiltIn { name: String },
+    #[error("component `{name}` is not a tag")]
+    NotATag { name: String },
+    #[error("`{name}` is state of the whole world and can only be added as a resource")]
+    NotAComponent { name: String },
+    #[error("component `{name}` is a tag and doesn't store any data")]
+    TagHasNoData { name: String },
+    #[error("`{name}` is already borrowed in a way that conflicts with this borrow")]
+    BorrowConflict { name: String },
+    #[error("entity with id `{entity_id}` doesn't have component `{name}`")]
+    NoSuchComponent { name: String, entity_id: u32 },
+    #[error("entity with id `{entity_id}` not found")]
+    NoSuchEntity { entity_id: u32 },
+    #[error("tried to access index `{index}` of a vector with a length of `{length}`")]
+    OutOfRangeInVector { index: usize, length: usize },
+    #[error("`{hex}` is not a valid hex color, expected `#RRGGBB` or `#RRGGBBAA`")]
+    InvalidHexColor { hex: String },
+}
+
+impl BbEcsError {
+    /// Fill in the name of the component or resource on casting errors, where the cast itself
+    /// doesn't know which key the value was stored under. Other errors are returned unchanged.
+    pub fn with_name(self, key: &str) -> Self {
+        match self {
+            BbEcsError::CastingComponents {
+                expected, found, ..
+            } => BbEcsError::CastingComponents {
+                name: Some(key.to_owned()),
+                expected,
+                found,
+            },
+            BbEcsError::CastingResource {
+                expected, found, ..
+            } => BbEcsError::CastingResource {
+                name: Some(key.to_owned()),
+                expected,
+                found,
+            },
+            error => error,
+        }
+    }
+}
+
+fn named(name: &Option<String>) -> String {
+    name.as_ref()
+        .map_or_else(String::new, |name| format!(" `{}`", name))
 }
@@ -0,0 +1,134 @@
+//! Moving entities by integrating their acceleration into their velocity and their velocity into
+//! their location every update.
+
+use std::cell::RefMut;
+
+use crate::components::CastComponents;
+use crate::data_types::point::Point;
+use crate::errors::Result;
+use crate::resources::resource::ResourceCast;
+use crate::world::World;
+
+/// How the location and velocity are advanced each update
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Integrator {
+    /// Move by the old velocity and then apply the acceleration, this gains energy over time
+    ExplicitEuler,
+    /// Apply the acceleration and then move by the new velocity, which stays stable for most games
+    #[default]
+    SemiImplicitEuler,
+    /// Move by the old velocity plus half of the acceleration, exact when the acceleration is
+    /// constant during the update
+    Verlet,
+}
+
+/// System that moves every entity with a location, velocity and acceleration component. The
+/// acceleration is reset to zero after it is applied so that forces can be added to it again
+/// before the next run.
+/// ```
+/// use bbecs::data_types::point::Point;
+/// use bbecs::kinematics::KinematicsSystem;
+/// use bbecs::world::{World, WorldMethods};
+///
+/// # fn main() -> eyre::Result<()> {
+/// let mut world = World::new();
+/// world.register("location")?;
+/// world.register("velocity")?;
+/// world.register("acceleration")?;
+/// world
+///     .spawn_entity()?
+///     .with_component("location", Point::new(0.0, 0.0))?
+///     .with_component("velocity", Point::new(1.0, 0.0))?
+///     .with_component("acceleration", Point::new(0.0, 2.0))?;
+///
+/// KinematicsSystem::new().run(&world)?;
+/// assert_eq!(*world.get::<Point>(0, "velocity")?, Point::new(1.0, 2.0));
+/// assert_eq!(*world.get::<Point>(0, "location")?, Point::new(1.0, 2.0));
+/// assert_eq!(*world.get::<Point>(0, "acceleration")?, Point::new(0.0, 0.0));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct KinematicsSystem {
+    pub location: String,
+    pub velocity: String,
+    pub acceleration: String,
+    pub integrator: Integrator,
+    /// How much of the velocity is lost per second (or per update without a delta time), 0.0 for
+    /// no drag and 1.0 to stop entirely
+    pub drag: f32,
+    pub max_speed: Option<f32>,
+    /// Name of the f32 resource with the time since the last update, without it every run moves
+    /// the entities by one update's worth of velocity
+    pub delta_time: Option<String>,
+}
+
+impl KinematicsSystem {
+    /// Create the system using the "location", "velocity" and "acceleration" components with no
+    /// drag, no max speed and no delta time
+    pub fn new() -> Self {
+        Self {
+            location: "location".to_owned(),
+            velocity: "velocity".to_owned(),
+            acceleration: "acceleration".to_owned(),
+            integrator: Integrator::default(),
+            drag: 0.0,
+            max_speed: None,
+            delta_time: None,
+        }
+    }
+
+    pub fn run(&self, world: &World) -> Result<()> {
+        let delta_time = match &self.delta_time {
+            Some(delta_time_resource) => {
+                let resource = world.borrow_resource(delta_time_resource.as_str())?;
+                let delta_time: &f32 = resource
+                    .cast()
+                    .map_err(|error| error.with_name(delta_time_resource))?;
+                *delta_time
+            }
+            None => 1.0,
+        };
+        let query = world.query(vec![
+            self.location.as_str(),
+            self.velocity.as_str(),
+            self.acceleration.as_str(),
+        ])?;
+        let locations = query.get(self.location.as_str()).unwrap();
+        let velocities = query.get(self.velocity.as_str()).unwrap();
+        let accelerations = query.get(self.acceleration.as_str()).unwrap();
+
+        for (index, location) in locations.iter().enumerate() {
+            let mut location: RefMut<Point> = location.try_borrow_mut(&self.location)?;
+            let mut velocity: RefMut<Point> = velocities[index].try_borrow_mut(&self.velocity)?;
+            let mut acceleration: RefMut<Point> =
+                accelerations[index].try_borrow_mut(&self.acceleration)?;
+
+            let old_velocity = *velocity;
+            *velocity += *acceleration * delta_time;
+            if self.drag > 0.0 {
+                *velocity *= (1.0 - self.drag * delta_time).max(0.0);
+            }
+            if let Some(max_speed) = self.max_speed {
+                velocity.clamp_length(max_speed);
+            }
+
+            *location += match self.integrator {
+                Integrator::ExplicitEuler => old_velocity * delta_time,
+                Integrator::SemiImplicitEuler => *velocity * delta_time,
+                Integrator::Verlet => {
+                    old_velocity * delta_time + *acceleration * (0.5 * delta_time * delta_time)
+                }
+            };
+            *acceleration = Point::new(0.0, 0.0);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for KinematicsSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,5 +1,9 @@
+pub mod boundary;
+pub mod collision;
 pub mod components;
 pub mod data_types;
 pub mod errors;
+pub mod kinematics;
 pub mod resources;
+pub mod steering;
 pub mod world;
@@ -1,4 +1,5 @@
-/// Get a reference to a resource in the world.
+/// Get a reference to a resource in the world. Errors are returned with `?`, including a
+/// `BbEcsError::BorrowConflict` if the resource is already mutably borrowed.
 ///
 /// ```
 /// use bbecs::world::World;
@@ -6,22 +7,29 @@
 /// use crate::bbecs::world::WorldMethods;
 /// use crate::bbecs::resources::resource::ResourceCast;
 ///
+/// # fn main() -> eyre::Result<()> {
 /// let mut world = World::new();
 /// world.add_resource("size".to_string(), 15.0_f32);
 /// let size: &f32;
 /// get_resource!(size, world, "size");
 /// assert!(*size == 15.0_f32);
+/// # Ok(())
+/// # }
 /// ```
 #[macro_export]
 macro_rules! get_resource {
     ($resource:ident, $world:expr, $name:expr) => {
-        let wrapper = $world.get_resource($name).unwrap().borrow();
-        $resource = wrapper.cast().unwrap();
+        let name: String = $name.into();
+        let wrapper = $world.borrow_resource(name.as_str())?;
+        $resource = wrapper
+            .cast()
+            .map_err(|error: $crate::errors::BbEcsError| error.with_name(&name))?;
     };
 }
 
 /// Get a mutable reference to a resource from the provided world so that we can change it. This will
-/// probably need to be put into a block or dropped so that other queries can work.
+/// probably need to be put into a block or dropped so that other queries can work. Errors are returned
+/// with `?`, including a `BbEcsError::BorrowConflict` if the resource is already borrowed.
 ///
 /// ```
 /// use bbecs::world::World;
@@ -29,6 +37,7 @@ macro_rules! get_resource {
 /// use crate::bbecs::world::WorldMethods;
 /// use crate::bbecs::resources::resource::ResourceCast;
 ///
+/// # fn main() -> eyre::Result<()> {
 /// let mut world = World::new();
 /// world.add_resource("size".to_string(), 15.0_f32);
 ///
@@ -42,11 +51,16 @@ macro_rules! get_resource {
 /// let size: &f32;
 /// get_resource!(size, world, "size");
 /// assert_eq!(*size, 16.0_f32);
+/// # Ok(())
+/// # }
 /// ```
 #[macro_export]
 macro_rules! get_resource_mut {
     ($resource:ident, $world:expr, $name:expr) => {
-        let mut wrapper = $world.get_resource($name).unwrap().borrow_mut();
-        $resource = wrapper.cast_mut().unwrap();
+        let name: String = $name.into();
+        let mut wrapper = $world.borrow_resource_mut(name.as_str())?;
+        $resource = wrapper
+            .cast_mut()
+            .map_err(|error: $crate::errors::BbEcsError| error.with_name(&name))?;
     };
 }
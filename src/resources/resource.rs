@@ -1,9 +1,21 @@
-use eyre::{bail, Result};
+#[cfg(feature = "ggez")]
 use ggez::audio::SoundData;
+#[cfg(feature = "ggez")]
 use ggez::event::KeyCode;
-use ggez::graphics::{Color, Mesh, Text, TextFragment};
+#[cfg(feature = "ggez")]
+use ggez::graphics::{Color as GgezColor, Mesh, Text, TextFragment};
 
+use crate::data_types::boundary::Boundary;
+use crate::data_types::circle::Circle;
+use crate::data_types::collider::Collider;
+use crate::data_types::color::Color;
+use crate::data_types::entity_ref::EntityRef;
 use crate::data_types::point::Point;
+use crate::data_types::rect::Rect;
+use crate::data_types::spatial_hash::SpatialHash;
+use crate::data_types::timer::Timer;
+use crate::data_types::transform::Transform;
+use crate::errors::{BbEcsError, Result};
 
 macro_rules! impl_resource_cast {
     ($new_type:ty, $arm:ident) => {
@@ -12,11 +24,11 @@ macro_rules! impl_resource_cast {
                 if let Resource::$arm(value) = self {
                     Ok(value)
                 } else {
-                    bail!(
-                        "Error casting {} to resource of type {}",
-                        format!("{:?}", self),
-                        stringify!($new_type)
-                    )
+                    Err(BbEcsError::CastingResource {
+                        name: None,
+                        expected: stringify!($new_type),
+                        found: self.type_name(),
+                    })
                 }
             }
 
@@ -24,11 +36,11 @@ macro_rules! impl_resource_cast {
                 if let Resource::$arm(value) = self {
                     Ok(value)
                 } else {
-                    bail!(
-                        "Error casting {} to resource of type {}",
-                        format!("{:?}", self),
-                        stringify!($new_type)
-                    )
+                    Err(BbEcsError::CastingResource {
+                        name: None,
+                        expected: stringify!($new_type),
+                        found: self.type_name(),
+                    })
                 }
             }
         }
@@ -42,29 +54,107 @@ pub trait ResourceCast<T> {
 
 #[derive(Debug, Clone)]
 pub enum Resource {
+    /// Holds the bbecs `Color` since 0.4.0, the ggez color moved to `GgezColor`
     Color(Color),
+    #[cfg(feature = "ggez")]
+    GgezColor(GgezColor),
+    #[cfg(feature = "ggez")]
     Mesh(Mesh),
     Point(Point),
     U32(u32),
     F32(f32),
     Usize(usize),
     Bool(bool),
+    #[cfg(feature = "ggez")]
     GgezKeyCode(KeyCode),
     Marker(String),
+    EntityRef(EntityRef),
+    PointList(Vec<Point>),
+    F32List(Vec<f32>),
+    Timer(Timer),
+    Rect(Rect),
+    Circle(Circle),
+    Transform(Transform),
+    SpatialHash(SpatialHash),
+    Collider(Collider),
+    Boundary(Boundary),
+    #[cfg(feature = "ggez")]
     GgezText(Text),
-    GgezSound(ggez::audio::SoundData),
+    #[cfg(feature = "ggez")]
+    GgezSound(SoundData),
+    #[cfg(feature = "ggez")]
     GgezTextFragment(TextFragment),
 }
 
+impl Resource {
+    /// Whether the resource keeps track of entities and has to be told when they are despawned
+    pub fn tracks_entities(&self) -> bool {
+        matches!(self, Resource::EntityRef(_) | Resource::SpatialHash(_))
+    }
+
+    /// The name of the type stored in this resource, used when reporting casting errors
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Resource::Color(_) => "Color",
+            #[cfg(feature = "ggez")]
+            Resource::GgezColor(_) => "GgezColor",
+            #[cfg(feature = "ggez")]
+            Resource::Mesh(_) => "Mesh",
+            Resource::Point(_) => "Point",
+            Resource::U32(_) => "u32",
+            Resource::F32(_) => "f32",
+            Resource::Usize(_) => "usize",
+            Resource::Bool(_) => "bool",
+            #[cfg(feature = "ggez")]
+            Resource::GgezKeyCode(_) => "KeyCode",
+            Resource::Marker(_) => "String",
+            Resource::EntityRef(_) => "EntityRef",
+            Resource::PointList(_) => "Vec<Point>",
+            Resource::F32List(_) => "Vec<f32>",
+            Resource::Timer(_) => "Timer",
+            Resource::Rect(_) => "Rect",
+            Resource::Circle(_) => "Circle",
+            Resource::Transform(_) => "Transform",
+            Resource::SpatialHash(_) => "SpatialHash",
+            Resource::Collider(_) => "Collider",
+            Resource::Boundary(_) => "Boundary",
+            #[cfg(feature = "ggez")]
+            Resource::GgezText(_) => "Text",
+            #[cfg(feature = "ggez")]
+            Resource::GgezSound(_) => "SoundData",
+            #[cfg(feature = "ggez")]
+            Resource::GgezTextFragment(_) => "TextFragment",
+        }
+    }
+}
+
 impl_resource_cast!(u32, U32);
 impl_resource_cast!(Point, Point);
 impl_resource_cast!(Color, Color);
-impl_resource_cast!(Mesh, Mesh);
 impl_resource_cast!(f32, F32);
 impl_resource_cast!(usize, Usize);
 impl_resource_cast!(bool, Bool);
-impl_resource_cast!(KeyCode, GgezKeyCode);
 impl_resource_cast!(String, Marker);
+impl_resource_cast!(EntityRef, EntityRef);
+impl_resource_cast!(Vec<Point>, PointList);
+impl_resource_cast!(Vec<f32>, F32List);
+impl_resource_cast!(Timer, Timer);
+impl_resource_cast!(Rect, Rect);
+impl_resource_cast!(Circle, Circle);
+impl_resource_cast!(Transform, Transform);
+impl_resource_cast!(SpatialHash, SpatialHash);
+impl_resource_cast!(Collider, Collider);
+impl_resource_cast!(Boundary, Boundary);
+
+#[cfg(feature = "ggez")]
+impl_resource_cast!(GgezColor, GgezColor);
+#[cfg(feature = "ggez")]
+impl_resource_cast!(Mesh, Mesh);
+#[cfg(feature = "ggez")]
+impl_resource_cast!(KeyCode, GgezKeyCode);
+#[cfg(feature = "ggez")]
 impl_resource_cast!(Text, GgezText);
+#[cfg(feature = "ggez")]
 impl_resource_cast!(SoundData, GgezSound);
+#[cfg(feature = "ggez")]
 impl_resource_cast!(TextFragment, GgezTextFragment);
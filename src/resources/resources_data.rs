@@ -1,15 +1,17 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
-use eyre::Result;
-
-use crate::errors::BbEcsError;
+use crate::errors::{BbEcsError, Result};
 
 use super::resource::Resource;
 
+#[derive(Default)]
 pub struct ResourcesData {
     resources: HashMap<String, Rc<RefCell<Resource>>>,
+    /// Names of the resources that track entities, kept separately so that they can be found
+    /// without borrowing resources that might be borrowed elsewhere
+    entity_trackers: HashSet<String>,
 }
 
 impl ResourcesData {
@@ -19,22 +21,37 @@ impl ResourcesData {
 
     /// Inserts a resource into storage, overwriting any resource that had the same name
     pub fn insert(&mut self, name: String, resource: Resource) {
+        if resource.tracks_entities() {
+            self.entity_trackers.insert(name.clone());
+        } else {
+            self.entity_trackers.remove(&name);
+        }
         self.resources.insert(name, Rc::new(RefCell::new(resource)));
     }
 
+    /// Removes every resource
+    pub fn clear(&mut self) {
+        self.resources.clear();
+        self.entity_trackers.clear();
+    }
+
+    /// Whether the resource with the given name was inserted as a resource that keeps track of
+    /// entities, see `Resource::tracks_entities`
+    pub fn tracks_entities(&self, name: &str) -> bool {
+        self.entity_trackers.contains(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Rc<RefCell<Resource>>)> {
+        self.resources.iter()
+    }
+
     pub fn get(&self, name: &str) -> Result<&Rc<RefCell<Resource>>> {
         if let Some(resource) = self.resources.get(name) {
             Ok(resource)
         } else {
-            Err(BbEcsError::ResourceNotFound(name.to_owned()).into())
-        }
-    }
-}
-
-impl Default for ResourcesData {
-    fn default() -> Self {
-        Self {
-            resources: HashMap::new(),
+            Err(BbEcsError::ResourceNotFound {
+                name: name.to_owned(),
+            })
         }
     }
 }
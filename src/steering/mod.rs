@@ -0,0 +1,216 @@
+//! Steering behaviors for moving entities around in a natural looking way. Every behavior returns a
+//! steering force which is the change in velocity needed to move at the desired velocity, and can
+//! be added to an acceleration component. Forces can be limited with `Point::clamp_length` before
+//! they are combined.
+
+use std::cell::{Ref, RefMut};
+
+use crate::components::CastComponents;
+use crate::data_types::point::Point;
+use crate::errors::Result;
+use crate::world::World;
+
+/// Steer straight towards the target at full speed
+/// ```
+/// use bbecs::data_types::point::Point;
+/// use bbecs::steering;
+/// let force = steering::seek(&Point::new(0.0, 0.0), &Point::new(0.0, 1.0), &Point::new(10.0, 0.0), 2.0);
+/// assert_eq!(force, Point::new(2.0, -1.0));
+/// ```
+pub fn seek(location: &Point, velocity: &Point, target: &Point, max_speed: f32) -> Point {
+    desired_velocity(*target - *location, max_speed) - *velocity
+}
+
+/// Steer straight away from the threat at full speed
+/// ```
+/// use bbecs::data_types::point::Point;
+/// use bbecs::steering;
+/// let force = steering::flee(&Point::new(0.0, 0.0), &Point::new(0.0, 0.0), &Point::new(10.0, 0.0), 2.0);
+/// assert_eq!(force, Point::new(-2.0, 0.0));
+/// ```
+pub fn flee(location: &Point, velocity: &Point, threat: &Point, max_speed: f32) -> Point {
+    desired_velocity(*location - *threat, max_speed) - *velocity
+}
+
+/// Seek the target but slow down once inside of the slowing radius so that the target is reached
+/// without overshooting. A slowing radius of 0.0 or less is the same as seeking.
+/// ```
+/// use bbecs::data_types::point::Point;
+/// use bbecs::steering;
+/// let force = steering::arrive(&Point::new(0.0, 0.0), &Point::new(0.0, 0.0), &Point::new(5.0, 0.0), 2.0, 10.0);
+/// assert_eq!(force, Point::new(1.0, 0.0));
+/// ```
+pub fn arrive(
+    location: &Point,
+    velocity: &Point,
+    target: &Point,
+    max_speed: f32,
+    slowing_radius: f32,
+) -> Point {
+    let to_target = *target - *location;
+    let distance = to_target.length();
+    let speed = if distance < slowing_radius {
+        max_speed * distance / slowing_radius
+    } else {
+        max_speed
+    };
+
+    desired_velocity(to_target, speed) - *velocity
+}
+
+/// Wander around by seeking a point on a circle projected in front of the entity. The wander angle
+/// is where on the circle to seek and should be nudged by a small random amount every frame to get
+/// a smooth random walk.
+/// ```
+/// use bbecs::data_types::point::Point;
+/// use bbecs::steering;
+/// let force = steering::wander(&Point::new(1.0, 0.0), 2.0, 1.0, std::f32::consts::FRAC_PI_2, 1.0);
+/// // an angle of a quarter turn is to the right of the heading, which is down on the screen
+/// assert!(force.y > 0.0);
+/// ```
+pub fn wander(
+    velocity: &Point,
+    wander_distance: f32,
+    wander_radius: f32,
+    wander_angle: f32,
+    max_speed: f32,
+) -> Point {
+    let heading = desired_velocity(*velocity, 1.0);
+    let circle_center = heading * wander_distance;
+    let mut displacement = Point::from_angle(wander_angle, wander_radius);
+    displacement.rotate(heading.rotation());
+
+    desired_velocity(circle_center + displacement, max_speed) - *velocity
+}
+
+/// Steer away from neighbors that are closer than the desired separation, closer neighbors push
+/// harder. Neighbors at exactly the same location are ignored because there is no direction to
+/// steer away from them.
+/// ```
+/// use bbecs::data_types::point::Point;
+/// use bbecs::steering;
+/// let neighbors = [Point::new(5.0, 0.0), Point::new(100.0, 0.0)];
+/// let force = steering::separation(&Point::new(0.0, 0.0), &Point::new(0.0, 0.0), &neighbors, 10.0, 2.0);
+/// assert_eq!(force, Point::new(-2.0, 0.0));
+/// ```
+pub fn separation(
+    location: &Point,
+    velocity: &Point,
+    neighbors: &[Point],
+    desired_separation: f32,
+    max_speed: f32,
+) -> Point {
+    let mut away = Point::new(0.0, 0.0);
+    let mut count = 0;
+
+    for neighbor in neighbors {
+        let difference = *location - *neighbor;
+        let distance = difference.length();
+        if distance > 0.0 && distance < desired_separation {
+            away += difference / (distance * distance);
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        return Point::new(0.0, 0.0);
+    }
+
+    desired_velocity(away, max_speed) - *velocity
+}
+
+/// The direction scaled to the speed, a zero length direction stays zero
+fn desired_velocity(mut direction: Point, speed: f32) -> Point {
+    if direction.length() == 0.0 {
+        return direction;
+    }
+
+    direction.normalize();
+    direction * speed
+}
+
+/// System that steers every entity with a location, velocity, target and acceleration component
+/// towards its target. The steering force is limited to the max force and added to the
+/// acceleration so that it can be combined with other forces.
+/// ```
+/// use bbecs::data_types::point::Point;
+/// use bbecs::steering::SteeringSystem;
+/// use bbecs::world::{World, WorldMethods};
+///
+/// # fn main() -> eyre::Result<()> {
+/// let mut world = World::new();
+/// world.register("location")?;
+/// world.register("velocity")?;
+/// world.register("target")?;
+/// world.register("acceleration")?;
+/// world
+///     .spawn_entity()?
+///     .with_component("location", Point::new(0.0, 0.0))?
+///     .with_component("velocity", Point::new(0.0, 0.0))?
+///     .with_component("target", Point::new(100.0, 0.0))?
+///     .with_component("acceleration", Point::new(0.0, 0.0))?;
+///
+/// SteeringSystem::new(5.0, 0.5).run(&world)?;
+/// assert_eq!(*world.get::<Point>(0, "acceleration")?, Point::new(0.5, 0.0));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteeringSystem {
+    pub location: String,
+    pub velocity: String,
+    pub target: String,
+    pub acceleration: String,
+    pub max_speed: f32,
+    pub max_force: f32,
+    /// Entities slow down when they are closer than this to their target, 0.0 to never slow down
+    pub slowing_radius: f32,
+}
+
+impl SteeringSystem {
+    /// Create the system using the "location", "velocity", "target" and "acceleration" components
+    pub fn new(max_speed: f32, max_force: f32) -> Self {
+        Self {
+            location: "location".to_owned(),
+            velocity: "velocity".to_owned(),
+            target: "target".to_owned(),
+            acceleration: "acceleration".to_owned(),
+            max_speed,
+            max_force,
+            slowing_radius: 0.0,
+        }
+    }
+
+    pub fn run(&self, world: &World) -> Result<()> {
+        let query = world.query(vec![
+            self.location.as_str(),
+            self.velocity.as_str(),
+            self.target.as_str(),
+            self.acceleration.as_str(),
+        ])?;
+        let locations = query.get(self.location.as_str()).unwrap();
+        let velocities = query.get(self.velocity.as_str()).unwrap();
+        let targets = query.get(self.target.as_str()).unwrap();
+        let accelerations = query.get(self.acceleration.as_str()).unwrap();
+
+        for (index, location) in locations.iter().enumerate() {
+            let location: Ref<Point> = location.try_borrow(&self.location)?;
+            let velocity: Ref<Point> = velocities[index].try_borrow(&self.velocity)?;
+            let target: Ref<Point> = targets[index].try_borrow(&self.target)?;
+            let mut acceleration: RefMut<Point> =
+                accelerations[index].try_borrow_mut(&self.acceleration)?;
+
+            let mut force = arrive(
+                &location,
+                &velocity,
+                &target,
+                self.max_speed,
+                self.slowing_radius,
+            );
+            force.clamp_length(self.max_force);
+            *acceleration += force;
+        }
+
+        Ok(())
+    }
+}
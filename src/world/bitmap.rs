@@ -1,66 +1,210 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::HashMap;
 
-use eyre::Result;
+use crate::errors::{BbEcsError, Result};
 
-use crate::errors::BbEcsError;
+use super::component_id::{ComponentId, ComponentKey, ComponentRegistry};
 
 #[derive(Debug, Default)]
 pub struct BitMap {
-    entity_map: HashMap<String, Vec<bool>>,
-    length: usize,
+    entity_map: Vec<Option<Vec<bool>>>,
+    /// The id of the entity at each index of the map
+    entity_ids: Vec<u32>,
+    /// The index in the map of each entity id, so that entities can be found without scanning
+    entity_indexes: HashMap<u32, usize>,
 }
 
 impl BitMap {
     pub fn new() -> Self {
-        Self {
-            entity_map: HashMap::new(),
-            length: 0,
+        Self::default()
+    }
+
+    /// Add a new component to the map. The component must be registered in the same order as the
+    /// `ComponentRegistry` so that the index in the map matches the id.
+    pub fn register(&mut self) {
+        self.entity_map.push(Some(vec![false; self.len()]));
+    }
+
+    pub fn unregister(&mut self, id: ComponentId) {
+        if let Some(components) = self.entity_map.get_mut(id.index()) {
+            *components = None;
         }
     }
 
-    pub fn register(&mut self, name: String) {
-        self.entity_map.insert(name, vec![]);
+    /// Remove every entity while keeping the registered components
+    pub fn clear(&mut self) {
+        self.entity_ids.clear();
+        self.entity_indexes.clear();
+        for components in self.entity_map.iter_mut().flatten() {
+            components.clear();
+        }
     }
 
-    pub fn spawn_entity(&mut self) {
-        self.length += 1;
-        for components in &mut self.entity_map.values_mut() {
+    pub fn spawn_entity(&mut self, entity_id: u32) {
+        self.entity_indexes.insert(entity_id, self.entity_ids.len());
+        self.entity_ids.push(entity_id);
+        for components in self.entity_map.iter_mut().flatten() {
             components.push(false);
         }
     }
 
-    pub fn insert(&mut self, name: &str) -> Result<()> {
-        if let Some(components) = self.entity_map.get_mut(name) {
-            components[self.length - 1] = true;
+    /// The number of entities in the map
+    pub fn len(&self) -> usize {
+        self.entity_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entity_ids.is_empty()
+    }
+
+    /// The ids of every entity in the order they are stored in the map
+    pub fn entity_ids(&self) -> &[u32] {
+        &self.entity_ids
+    }
+
+    /// Where the entity with the given id is stored in the map, returns None if there isn't an
+    /// entity with that id
+    pub fn entity_index(&self, entity_id: u32) -> Option<usize> {
+        self.entity_indexes.get(&entity_id).copied()
+    }
+
+    /// The ids of every registered component
+    pub fn registered(&self) -> Vec<ComponentId> {
+        self.entity_map
+            .iter()
+            .enumerate()
+            .filter(|(_, components)| components.is_some())
+            .map(|(index, _)| ComponentId::new(index))
+            .collect()
+    }
+
+    /// The ids of every component that the entity at the given index has
+    pub fn components_of(&self, entity_index: usize) -> Result<Vec<ComponentId>> {
+        if entity_index >= self.len() {
+            return Err(BbEcsError::OutOfRangeInVector {
+                index: entity_index,
+                length: self.len(),
+            });
+        }
+
+        Ok(self
+            .entity_map
+            .iter()
+            .enumerate()
+            .filter(|(_, components)| {
+                components
+                    .as_ref()
+                    .is_some_and(|components| components[entity_index])
+            })
+            .map(|(index, _)| ComponentId::new(index))
+            .collect())
+    }
+
+    /// Where the component for the entity at the given index is stored in that component's data,
+    /// returns None if the entity doesn't have the component
+    pub fn component_index(&self, id: ComponentId, entity_index: usize) -> Option<usize> {
+        let components = self.entity_map.get(id.index())?.as_ref()?;
+
+        if !*components.get(entity_index)? {
+            return None;
+        }
+
+        let falses_before = components[0..entity_index]
+            .iter()
+            .filter(|has_component| !**has_component)
+            .count();
+        Some(entity_index - falses_before)
+    }
+
+    /// Set whether the entity at the given index has the component. This doesn't move any component
+    /// data so it should only be used for tags.
+    pub fn set(
+        &mut self,
+        id: ComponentId,
+        entity_index: usize,
+        has_component: bool,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        let components = match self.entity_map.get_mut(id.index()) {
+            Some(Some(components)) => components,
+            _ => {
+                return Err(BbEcsError::BitMapComponentNotFound {
+                    name: id.describe(registry),
+                })
+            }
+        };
+        let length = components.len();
+        let component = components
+            .get_mut(entity_index)
+            .ok_or(BbEcsError::OutOfRangeInVector {
+                index: entity_index,
+                length,
+            })?;
+
+        *component = has_component;
+        Ok(())
+    }
+
+    /// Whether the entity at the given index has the component
+    pub fn has(&self, id: ComponentId, entity_index: usize) -> bool {
+        matches!(
+            self.entity_map.get(id.index()),
+            Some(Some(components)) if components.get(entity_index) == Some(&true)
+        )
+    }
+
+    pub fn insert(&mut self, id: ComponentId, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.entity_map.get_mut(id.index()) {
+            components[self.entity_ids.len() - 1] = true;
         } else {
-            return Err(BbEcsError::BitMapInsertBeforeRegister.into());
+            return Err(BbEcsError::BitMapInsertBeforeRegister {
+                name: id.describe(registry),
+            });
         }
 
         Ok(())
     }
 
-    pub fn query(&self, names: Vec<&str>) -> Result<BTreeMap<String, &Vec<bool>>> {
-        let mut results = BTreeMap::new();
+    pub fn query(
+        &self,
+        ids: &[ComponentId],
+        registry: &ComponentRegistry,
+    ) -> Result<Vec<(ComponentId, &Vec<bool>)>> {
+        let mut results: Vec<(ComponentId, &Vec<bool>)> = Vec::with_capacity(ids.len());
 
-        for name in names {
-            if let Some(map) = self.entity_map.get(name) {
-                results.insert(name.to_owned(), map);
+        for id in ids {
+            if results.iter().any(|(queried_id, _)| queried_id == id) {
+                continue;
+            }
+
+            if let Some(Some(map)) = self.entity_map.get(id.index()) {
+                results.push((*id, map));
             } else {
-                return Err(BbEcsError::BitMapComponentNotFound(name.to_owned()).into());
+                return Err(BbEcsError::BitMapComponentNotFound {
+                    name: id.describe(registry),
+                });
             }
         }
 
         Ok(results)
     }
 
+    /// For every component, calculate where the deleted entities are stored in that component's
+    /// data. The outer vector is indexed by the component id.
     pub fn calculate_component_indexes_to_delete(
         &self,
         entity_indexes: &[usize],
-    ) -> Result<HashMap<String, Vec<usize>>> {
-        let mut component_indexes_to_delete = HashMap::new();
+    ) -> Result<Vec<Vec<usize>>> {
+        let mut component_indexes_to_delete = Vec::with_capacity(self.entity_map.len());
 
-        for (component_name, bitmap) in &self.entity_map {
+        for bitmap in &self.entity_map {
             let mut indexes_to_delete = vec![];
+            let bitmap = match bitmap {
+                Some(bitmap) => bitmap,
+                None => {
+                    component_indexes_to_delete.push(indexes_to_delete);
+                    continue;
+                }
+            };
 
             for entity_index in entity_indexes {
                 if !bitmap[*entity_index] {
@@ -71,7 +215,7 @@ impl BitMap {
                     .push(entity_index - self.count_falses_before_index(bitmap, *entity_index)?);
             }
 
-            component_indexes_to_delete.insert(component_name.to_owned(), indexes_to_delete);
+            component_indexes_to_delete.push(indexes_to_delete);
         }
 
         Ok(component_indexes_to_delete)
@@ -79,18 +223,31 @@ impl BitMap {
 
     pub fn delete_entities_by_index(&mut self, mut entity_indexes: Vec<usize>) -> Result<()> {
         entity_indexes.reverse();
-        for components in self.entity_map.values_mut() {
+        for components in self.entity_map.iter_mut().flatten() {
             for entity_index in &entity_indexes {
                 components.remove(*entity_index);
             }
         }
-        self.length -= entity_indexes.len();
+
+        for entity_index in &entity_indexes {
+            let entity_id = self.entity_ids.remove(*entity_index);
+            self.entity_indexes.remove(&entity_id);
+        }
+        // only the entities after the first deleted entity moved
+        if let Some(first_deleted) = entity_indexes.last() {
+            for (index, entity_id) in self.entity_ids.iter().enumerate().skip(*first_deleted) {
+                self.entity_indexes.insert(*entity_id, index);
+            }
+        }
         Ok(())
     }
 
     fn count_falses_before_index(&self, components: &[bool], index: usize) -> Result<usize> {
         if index >= components.len() {
-            return Err(BbEcsError::OutOfRangeInVector.into());
+            return Err(BbEcsError::OutOfRangeInVector {
+                index,
+                length: components.len(),
+            });
         }
 
         let total_falses: &Vec<bool> = &components[0..index]
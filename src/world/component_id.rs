@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use crate::errors::{BbEcsError, Result};
+
+/// Cheap handle to a registered component that is returned by `World::register`. Inserting and
+/// querying with the id instead of the name skips hashing and allocating strings. Ids are only
+/// meaningful for the world that created them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ComponentId(usize);
+
+impl ComponentId {
+    pub(crate) fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    /// The position of the component in the world's storage
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Anything that can identify a registered component, either the `ComponentId` or the name that
+/// was used to register it.
+pub trait ComponentKey {
+    /// Find the id in the registry, returning None if the component isn't registered
+    fn lookup(&self, registry: &ComponentRegistry) -> Option<ComponentId>;
+
+    /// A human readable name for the component, used when reporting errors
+    fn describe(&self, registry: &ComponentRegistry) -> String;
+}
+
+impl ComponentKey for ComponentId {
+    fn lookup(&self, registry: &ComponentRegistry) -> Option<ComponentId> {
+        registry.name(*self).map(|_| *self)
+    }
+
+    fn describe(&self, registry: &ComponentRegistry) -> String {
+        registry
+            .name(*self)
+            .map(str::to_owned)
+            .unwrap_or_else(|| format!("{:?}", self))
+    }
+}
+
+impl ComponentKey for &str {
+    fn lookup(&self, registry: &ComponentRegistry) -> Option<ComponentId> {
+        registry.id(self)
+    }
+
+    fn describe(&self, _registry: &ComponentRegistry) -> String {
+        (*self).to_owned()
+    }
+}
+
+impl ComponentKey for String {
+    fn lookup(&self, registry: &ComponentRegistry) -> Option<ComponentId> {
+        registry.id(self)
+    }
+
+    fn describe(&self, _registry: &ComponentRegistry) -> String {
+        self.clone()
+    }
+}
+
+/// Maps component names to their ids and back again. Ids of unregistered components are never
+/// reused so that stale ids can't point at a different component.
+#[derive(Debug, Default)]
+pub struct ComponentRegistry {
+    names: Vec<Option<String>>,
+    ids: HashMap<String, ComponentId>,
+    tags: Vec<bool>,
+}
+
+impl ComponentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, name: String) -> Result<ComponentId> {
+        self.insert(name, false)
+    }
+
+    /// Register a tag, which is a component that only exists in the bitmap and has no data
+    pub fn register_tag(&mut self, name: String) -> Result<ComponentId> {
+        self.insert(name, true)
+    }
+
+    pub fn is_tag(&self, id: ComponentId) -> bool {
+        self.tags.get(id.0).copied().unwrap_or(false)
+    }
+
+    /// Remove the component, returning the name it was registered with
+    pub fn unregister(&mut self, id: ComponentId) -> Option<String> {
+        let name = self.names.get_mut(id.0)?.take()?;
+        self.ids.remove(&name);
+        Some(name)
+    }
+
+    pub fn id(&self, name: &str) -> Option<ComponentId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: ComponentId) -> Option<&str> {
+        self.names.get(id.0)?.as_deref()
+    }
+
+    /// Look up the key, using the provided function to build the error from the key's name if the
+    /// component isn't registered
+    pub fn resolve<K: ComponentKey>(
+        &self,
+        key: &K,
+        error: impl FnOnce(String) -> BbEcsError,
+    ) -> Result<ComponentId> {
+        key.lookup(self).ok_or_else(|| error(key.describe(self)))
+    }
+
+    fn insert(&mut self, name: String, is_tag: bool) -> Result<ComponentId> {
+        if self.ids.contains_key(&name) {
+            return Err(BbEcsError::ComponentAlreadyRegistered { name });
+        }
+
+        let id = ComponentId(self.names.len());
+        self.names.push(Some(name.clone()));
+        self.ids.insert(name, id);
+        self.tags.push(is_tag);
+        Ok(id)
+    }
+}
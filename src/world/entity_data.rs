@@ -1,47 +1,91 @@
 use std::cell::RefCell;
-use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
-use eyre::Result;
+#[cfg(feature = "ggez")]
+use ggez::audio::SoundData;
+#[cfg(feature = "ggez")]
 use ggez::event::KeyCode;
-use ggez::graphics::{Color, Mesh, Text, TextFragment};
+#[cfg(feature = "ggez")]
+use ggez::graphics::{Color as GgezColor, Mesh, Text, TextFragment};
 
-use crate::errors::BbEcsError;
-use crate::{components::ComponentData, data_types::point::Point};
+use crate::components::ComponentData;
+use crate::data_types::boundary::Boundary;
+use crate::data_types::circle::Circle;
+use crate::data_types::collider::Collider;
+use crate::data_types::color::Color;
+use crate::data_types::entity_ref::EntityRef;
+use crate::data_types::point::Point;
+use crate::data_types::rect::Rect;
+use crate::data_types::timer::Timer;
+use crate::data_types::transform::Transform;
+use crate::errors::{BbEcsError, Result};
+
+use super::component_id::{ComponentId, ComponentKey, ComponentRegistry};
 
 pub trait EntityDataTraits<T> {
-    fn insert(&mut self, name: &str, data: T) -> Result<()>;
+    /// Store the data for the most recently spawned entity, the registry is only used to name the
+    /// component in errors
+    fn insert(&mut self, id: ComponentId, data: T, registry: &ComponentRegistry) -> Result<()>;
 }
 
 #[derive(Debug, Default)]
 pub struct EntityData {
-    pub components: HashMap<String, Vec<ComponentData>>,
+    /// The stored components for each registered component, indexed by the component id. Unregistered
+    /// components are None.
+    pub components: Vec<Option<Vec<ComponentData>>>,
 }
 
 impl EntityData {
     pub fn new() -> Self {
-        Self {
-            components: HashMap::new(),
+        Self { components: vec![] }
+    }
+
+    /// Add storage for a new component. The component must be registered in the same order as the
+    /// `ComponentRegistry` so that the index of the storage matches the id.
+    pub fn register(&mut self) {
+        self.components.push(Some(vec![]));
+    }
+
+    /// Tags don't store any data, but still take up an index so that the component ids line up
+    pub fn register_tag(&mut self) {
+        self.components.push(None);
+    }
+
+    pub fn unregister(&mut self, id: ComponentId) {
+        if let Some(components) = self.components.get_mut(id.index()) {
+            *components = None;
         }
     }
 
-    pub fn register(&mut self, name: String) -> Result<()> {
-        if self.components.contains_key(&name) {
-            return Err(BbEcsError::ComponentAlreadyRegistered(name).into());
+    /// Get the stored data for a component, returns None if the component isn't registered
+    pub fn get(&self, id: ComponentId) -> Option<&Vec<ComponentData>> {
+        self.components.get(id.index())?.as_ref()
+    }
+
+    /// Remove the data for every entity while keeping the registered components
+    pub fn clear(&mut self) {
+        for components in self.components.iter_mut().flatten() {
+            components.clear();
         }
-        let components = vec![];
-        self.components.insert(name, components);
-        Ok(())
     }
 
-    pub fn query(
-        &self,
-        bitmap: BTreeMap<String, &Vec<bool>>,
-    ) -> Result<HashMap<String, Vec<&ComponentData>>> {
-        let mut results = HashMap::new();
+    /// Get the components of every entity that has all of the components in the bitmap. Tags are
+    /// only used to filter the entities, so their lists in the results are empty.
+    pub fn query<'a>(
+        &'a self,
+        bitmap: Vec<(ComponentId, &Vec<bool>)>,
+        tags: &[ComponentId],
+        registry: &ComponentRegistry,
+    ) -> Result<Vec<(ComponentId, Vec<&'a ComponentData>)>> {
+        let mut results = Vec::with_capacity(bitmap.len());
+
+        for (id, map) in bitmap.iter() {
+            if tags.contains(id) {
+                results.push((*id, vec![]));
+                continue;
+            }
 
-        for (name, map) in bitmap.iter() {
-            if let Some(components_list) = self.components.get(name) {
+            if let Some(Some(components_list)) = self.components.get(id.index()) {
                 let mut missing_components_count = 0;
                 let mut component_results = vec![];
                 for (index, component_map) in map.iter().enumerate() {
@@ -54,9 +98,11 @@ impl EntityData {
                         missing_components_count += 1;
                     }
                 }
-                results.insert(name.to_owned(), component_results);
+                results.push((*id, component_results));
             } else {
-                return Err(BbEcsError::ComponentNotFound(name.to_owned()).into());
+                return Err(BbEcsError::ComponentNotFound {
+                    name: id.describe(registry),
+                });
             }
         }
 
@@ -65,10 +111,10 @@ impl EntityData {
 
     fn does_entity_have_all_components(
         &self,
-        bitmap: &BTreeMap<String, &Vec<bool>>,
+        bitmap: &[(ComponentId, &Vec<bool>)],
         entity_index: usize,
     ) -> bool {
-        for components in bitmap.values() {
+        for (_, components) in bitmap {
             if !components[entity_index] {
                 return false;
             }
@@ -77,13 +123,16 @@ impl EntityData {
         true
     }
 
-    pub fn delete_entities_by_index(&mut self, bitmap: HashMap<String, Vec<usize>>) -> Result<()> {
-        for (component_name, mut indexes_to_delete) in bitmap {
+    /// Delete the components at the given indexes, the outer vector is indexed by the component id
+    pub fn delete_entities_by_index(&mut self, indexes: Vec<Vec<usize>>) -> Result<()> {
+        for (components, mut indexes_to_delete) in self.components.iter_mut().zip(indexes) {
+            let components = match components {
+                Some(components) => components,
+                None => continue,
+            };
             indexes_to_delete.reverse();
-            if let Some(components) = self.components.get_mut(&component_name) {
-                for index in indexes_to_delete {
-                    components.remove(index);
-                }
+            for index in indexes_to_delete {
+                components.remove(index);
             }
         }
 
@@ -92,132 +141,352 @@ impl EntityData {
 }
 
 impl EntityDataTraits<Point> for EntityData {
-    fn insert(&mut self, name: &str, data: Point) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(&mut self, id: ComponentId, data: Point, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::Point(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
 impl EntityDataTraits<Color> for EntityData {
-    fn insert(&mut self, name: &str, data: Color) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(&mut self, id: ComponentId, data: Color, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::Color(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ggez")]
+impl EntityDataTraits<GgezColor> for EntityData {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: GgezColor,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::GgezColor(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
 impl EntityDataTraits<f32> for EntityData {
-    fn insert(&mut self, name: &str, data: f32) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(&mut self, id: ComponentId, data: f32, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::F32(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
+#[cfg(feature = "ggez")]
 impl EntityDataTraits<Mesh> for EntityData {
-    fn insert(&mut self, name: &str, data: Mesh) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(&mut self, id: ComponentId, data: Mesh, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::Mesh(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
 impl EntityDataTraits<u32> for EntityData {
-    fn insert(&mut self, name: &str, data: u32) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(&mut self, id: ComponentId, data: u32, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::U32(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
 impl EntityDataTraits<usize> for EntityData {
-    fn insert(&mut self, name: &str, data: usize) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(&mut self, id: ComponentId, data: usize, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::Usize(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
 impl EntityDataTraits<bool> for EntityData {
-    fn insert(&mut self, name: &str, data: bool) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(&mut self, id: ComponentId, data: bool, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::Bool(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
+#[cfg(feature = "ggez")]
 impl EntityDataTraits<KeyCode> for EntityData {
-    fn insert(&mut self, name: &str, data: KeyCode) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: KeyCode,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::GgezKeyCode(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
 impl EntityDataTraits<String> for EntityData {
-    fn insert(&mut self, name: &str, data: String) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: String,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::Marker(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EntityDataTraits<EntityRef> for EntityData {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: EntityRef,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::EntityRef(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EntityDataTraits<Vec<Point>> for EntityData {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: Vec<Point>,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::PointList(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EntityDataTraits<Vec<f32>> for EntityData {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: Vec<f32>,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::F32List(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EntityDataTraits<Timer> for EntityData {
+    fn insert(&mut self, id: ComponentId, data: Timer, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::Timer(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EntityDataTraits<Rect> for EntityData {
+    fn insert(&mut self, id: ComponentId, data: Rect, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::Rect(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EntityDataTraits<Circle> for EntityData {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: Circle,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::Circle(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EntityDataTraits<Transform> for EntityData {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: Transform,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::Transform(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EntityDataTraits<Collider> for EntityData {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: Collider,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::Collider(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl EntityDataTraits<Boundary> for EntityData {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: Boundary,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
+            components.push(ComponentData::Boundary(Rc::new(RefCell::new(data))));
+        } else {
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
+#[cfg(feature = "ggez")]
 impl EntityDataTraits<Text> for EntityData {
-    fn insert(&mut self, name: &str, data: Text) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(&mut self, id: ComponentId, data: Text, registry: &ComponentRegistry) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::GgezText(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
-impl EntityDataTraits<ggez::audio::SoundData> for EntityData {
-    fn insert(&mut self, name: &str, data: ggez::audio::SoundData) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+#[cfg(feature = "ggez")]
+impl EntityDataTraits<SoundData> for EntityData {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: SoundData,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::GgezSound(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
 }
 
+#[cfg(feature = "ggez")]
 impl EntityDataTraits<TextFragment> for EntityData {
-    fn insert(&mut self, name: &str, data: TextFragment) -> Result<()> {
-        if let Some(components) = self.components.get_mut(name) {
+    fn insert(
+        &mut self,
+        id: ComponentId,
+        data: TextFragment,
+        registry: &ComponentRegistry,
+    ) -> Result<()> {
+        if let Some(Some(components)) = self.components.get_mut(id.index()) {
             components.push(ComponentData::GgezTextFragment(Rc::new(RefCell::new(data))));
         } else {
-            return Err(BbEcsError::NeedToRegister.into());
+            return Err(BbEcsError::NeedToRegister {
+                name: id.describe(registry),
+            });
         }
         Ok(())
     }
@@ -1,30 +1,59 @@
 pub mod bitmap;
+pub mod component_id;
 mod entity_data;
+pub mod query_results;
 
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::cell::{Ref, RefCell, RefMut};
 use std::rc::Rc;
 
 use entity_data::EntityData;
-use eyre::Result;
+#[cfg(feature = "ggez")]
 use ggez::audio::SoundData;
+#[cfg(feature = "ggez")]
 use ggez::event::KeyCode;
-use ggez::graphics::{Color, Mesh, Text, TextFragment};
+#[cfg(feature = "ggez")]
+use ggez::graphics::{Color as GgezColor, Mesh, Text, TextFragment};
 
 use crate::components::{CastComponents, ComponentData};
+use crate::data_types::boundary::Boundary;
+use crate::data_types::circle::Circle;
+use crate::data_types::collider::Collider;
+use crate::data_types::collision_layers::CollisionLayers;
+use crate::data_types::color::Color;
+use crate::data_types::entity_ref::EntityRef;
 use crate::data_types::point::Point;
-use crate::resources::resource::Resource;
+use crate::data_types::rect::Rect;
+use crate::data_types::rng::Rng;
+use crate::data_types::spatial_hash::SpatialHash;
+use crate::data_types::timer::Timer;
+use crate::data_types::transform::Transform;
+use crate::errors::{BbEcsError, Result};
+use crate::resources::resource::{Resource, ResourceCast};
 use crate::resources::resources_data::ResourcesData;
 
 use self::bitmap::BitMap;
+use self::component_id::{ComponentId, ComponentKey, ComponentRegistry};
 use self::entity_data::EntityDataTraits;
+use self::query_results::QueryResults;
 
 macro_rules! impl_world_trait {
     ($new_type:ty, $arm:ident) => {
         impl WorldMethods<$new_type> for World {
-            fn with_component(&mut self, name: &str, data: $new_type) -> Result<&mut Self> {
-                self.entity_data.insert(name, data)?;
-                self.bitmap.insert(name)?;
+            fn with_component<K: ComponentKey>(
+                &mut self,
+                key: K,
+                data: $new_type,
+            ) -> Result<&mut Self> {
+                let id = self
+                    .components
+                    .resolve(&key, |name| BbEcsError::NeedToRegister { name })?;
+                if self.components.is_tag(id) {
+                    return Err(BbEcsError::TagHasNoData {
+                        name: key.describe(&self.components),
+                    });
+                }
+                self.entity_data.insert(id, data, &self.components)?;
+                self.bitmap.insert(id, &self.components)?;
                 Ok(self)
             }
 
@@ -35,22 +64,50 @@ macro_rules! impl_world_trait {
     };
 }
 
-const TO_BE_DELETED: &str = "to be deleted";
+/// Types that only make sense for the whole world, which can be added as resources but not as
+/// components
+macro_rules! impl_world_resource {
+    ($new_type:ty, $arm:ident) => {
+        impl WorldMethods<$new_type> for World {
+            fn with_component<K: ComponentKey>(
+                &mut self,
+                key: K,
+                _data: $new_type,
+            ) -> Result<&mut Self> {
+                Err(BbEcsError::NotAComponent {
+                    name: key.describe(&self.components),
+                })
+            }
+
+            fn add_resource(&mut self, name: String, data: $new_type) {
+                self.resources.insert(name, Resource::$arm(data));
+            }
+        }
+    };
+}
+
+/// Bool component that every entity has, entities where it is true are deleted on the next update
+pub const TO_BE_DELETED: &str = "to be deleted";
 pub const ENTITY_ID: &str = "entity id";
 
 pub type DataWrapper<T> = Rc<RefCell<T>>;
 
 pub trait WorldMethods<T> {
-    fn with_component(&mut self, name: &str, data: T) -> Result<&mut Self>;
+    /// Add a component to the most recently spawned entity. The component can be identified by
+    /// the `ComponentId` returned from `World::register` or by its name.
+    fn with_component<K: ComponentKey>(&mut self, key: K, data: T) -> Result<&mut Self>;
     fn add_resource(&mut self, name: String, data: T);
 }
 
 pub struct World {
     pub entity_data: EntityData,
     resources: ResourcesData,
-    is_empty: bool,
     next_entity_id: u32,
     bitmap: BitMap,
+    components: ComponentRegistry,
+    to_be_deleted_id: ComponentId,
+    entity_id_id: ComponentId,
+    rng: Rng,
 }
 
 impl World {
@@ -58,48 +115,413 @@ impl World {
         Self::default()
     }
 
-    pub fn register<S: ToString>(&mut self, name: S) -> Result<()> {
-        self.entity_data.register(name.to_string())?;
-        self.bitmap.register(name.to_string());
+    /// Register a component so that it can be added to entities. The returned `ComponentId` can be
+    /// used instead of the name when inserting and querying, which avoids hashing the name.
+    pub fn register<S: ToString>(&mut self, name: S) -> Result<ComponentId> {
+        let id = self.components.register(name.to_string())?;
+        self.entity_data.register();
+        self.bitmap.register();
+        Ok(id)
+    }
+
+    /// Register a tag, which is a component that doesn't store any data. Tags are added to entities
+    /// with `tag` or `with_tag`, and can be used in queries to filter the entities.
+    ///
+    /// ```
+    /// use bbecs::world::{World, WorldMethods};
+    /// use bbecs::data_types::point::Point;
+    ///
+    /// # fn main() -> eyre::Result<()> {
+    /// let mut world = World::new();
+    /// world.register("location")?;
+    /// world.register_tag("enemy")?;
+    ///
+    /// world
+    ///     .spawn_entity()?
+    ///     .with_component("location", Point::new(0.0, 0.0))?
+    ///     .with_tag("enemy")?;
+    /// world
+    ///     .spawn_entity()?
+    ///     .with_component("location", Point::new(10.0, 10.0))?;
+    ///
+    /// let query = world.query(vec!["location", "enemy"])?;
+    /// assert_eq!(query.get("location").unwrap().len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_tag<S: ToString>(&mut self, name: S) -> Result<ComponentId> {
+        let id = self.components.register_tag(name.to_string())?;
+        self.entity_data.register_tag();
+        self.bitmap.register();
+        Ok(id)
+    }
+
+    /// Add a tag to the most recently spawned entity
+    pub fn with_tag<K: ComponentKey>(&mut self, key: K) -> Result<&mut Self> {
+        let id = self.tag_id(key)?;
+        self.bitmap.insert(id, &self.components)?;
+        Ok(self)
+    }
+
+    /// Add a tag to the entity with the given id
+    pub fn tag<K: ComponentKey>(&mut self, entity_id: u32, key: K) -> Result<()> {
+        self.set_tag(entity_id, key, true)
+    }
+
+    /// Remove a tag from the entity with the given id
+    pub fn untag<K: ComponentKey>(&mut self, entity_id: u32, key: K) -> Result<()> {
+        self.set_tag(entity_id, key, false)
+    }
+
+    /// Whether the entity with the given id has the tag
+    pub fn has_tag<K: ComponentKey>(&self, entity_id: u32, key: K) -> Result<bool> {
+        let id = self.tag_id(key)?;
+        let entity_index = self.entity_index(entity_id)?;
+
+        Ok(self.bitmap.has(id, entity_index))
+    }
+
+    fn set_tag<K: ComponentKey>(&mut self, entity_id: u32, key: K, has_tag: bool) -> Result<()> {
+        let id = self.tag_id(key)?;
+        let entity_index = self.entity_index(entity_id)?;
+
+        self.bitmap.set(id, entity_index, has_tag, &self.components)
+    }
+
+    fn tag_id<K: ComponentKey>(&self, key: K) -> Result<ComponentId> {
+        let id = self
+            .components
+            .resolve(&key, |name| BbEcsError::ComponentNotFound { name })?;
+
+        if !self.components.is_tag(id) {
+            return Err(BbEcsError::NotATag {
+                name: key.describe(&self.components),
+            });
+        }
+
+        Ok(id)
+    }
+
+    /// Remove a registered component along with the data every entity has for it. The component
+    /// can be registered again afterwards, but it will get a new id.
+    pub fn unregister<K: ComponentKey>(&mut self, key: K) -> Result<()> {
+        let id = self
+            .components
+            .resolve(&key, |name| BbEcsError::ComponentNotFound { name })?;
+
+        if id == self.to_be_deleted_id || id == self.entity_id_id {
+            return Err(BbEcsError::CannotUnregisterBuiltIn {
+                name: key.describe(&self.components),
+            });
+        }
+
+        self.components.unregister(id);
+        self.entity_data.unregister(id);
+        self.bitmap.unregister(id);
         Ok(())
     }
 
+    /// Remove every entity while keeping the registered components and resources. When
+    /// `reset_entity_ids` is true the next spawned entity will have an id of 0 again. `EntityRef`
+    /// resources are told that every entity was despawned and `SpatialHash` resources are emptied
+    /// so that they can't end up pointing at a new entity that reuses an id. If one of them is
+    /// already borrowed a `BbEcsError::BorrowConflict` is returned before anything is removed.
+    pub fn clear_entities(&mut self, reset_entity_ids: bool) -> Result<()> {
+        let despawned_entity_ids = self.bitmap.entity_ids().to_vec();
+        let (_, listening_resources) = self.despawn_listeners();
+        let mut resources = borrow_all_mut(&listening_resources)?;
+
+        self.entity_data.clear();
+        self.bitmap.clear();
+
+        if reset_entity_ids {
+            self.next_entity_id = 0;
+        }
+
+        for resource in resources.iter_mut() {
+            match &mut **resource {
+                Resource::EntityRef(entity_ref) => {
+                    entity_ref.entities_despawned(&despawned_entity_ids)
+                }
+                Resource::SpatialHash(spatial_hash) => spatial_hash.clear(),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove every resource, registered components and entities are kept
+    pub fn clear_resources(&mut self) {
+        self.resources.clear();
+    }
+
+    /// Look up the id of a registered component
+    pub fn component_id<K: ComponentKey>(&self, key: K) -> Result<ComponentId> {
+        self.components
+            .resolve(&key, |name| BbEcsError::ComponentNotFound { name })
+    }
+
+    /// Look up the name that a component was registered with, mostly useful for debugging
+    pub fn component_name(&self, id: ComponentId) -> Option<&str> {
+        self.components.name(id)
+    }
+
+    /// The number of entities in the world, including entities that will be deleted on the next
+    /// update
+    pub fn len(&self) -> usize {
+        self.bitmap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bitmap.is_empty()
+    }
+
+    /// The names of every registered component in the order they were registered. The components
+    /// that the world adds to every entity, like `ENTITY_ID`, are left out.
+    pub fn registered_components(&self) -> Vec<&str> {
+        self.bitmap
+            .registered()
+            .into_iter()
+            .filter(|id| !self.is_internal(*id))
+            .filter_map(|id| self.components.name(id))
+            .collect()
+    }
+
+    /// The names of every component that the entity with the given id has, leaving out the
+    /// components that the world adds to every entity
+    pub fn components_of(&self, entity_id: u32) -> Result<Vec<&str>> {
+        let entity_index = self.entity_index(entity_id)?;
+
+        Ok(self
+            .bitmap
+            .components_of(entity_index)?
+            .into_iter()
+            .filter(|id| !self.is_internal(*id))
+            .filter_map(|id| self.components.name(id))
+            .collect())
+    }
+
+    /// Whether the component is one that the world adds to every entity
+    fn is_internal(&self, id: ComponentId) -> bool {
+        id == self.to_be_deleted_id || id == self.entity_id_id
+    }
+
+    /// Immutably borrow a single component of the entity with the given id. Returns
+    /// `BbEcsError::NoSuchEntity` if there isn't an entity with that id, and
+    /// `BbEcsError::NoSuchComponent` if the entity doesn't have the component.
+    ///
+    /// ```
+    /// use bbecs::world::{World, WorldMethods};
+    ///
+    /// # fn main() -> eyre::Result<()> {
+    /// let mut world = World::new();
+    /// world.register("health")?;
+    /// world.spawn_entity()?.with_component("health", 100_u32)?;
+    ///
+    /// let health = world.get::<u32>(0, "health")?;
+    /// assert_eq!(*health, 100);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get<T>(&self, entity_id: u32, key: impl ComponentKey) -> Result<Ref<'_, T>>
+    where
+        ComponentData: CastComponents<T>,
+    {
+        let (component, name) = self.entity_component(entity_id, key)?;
+        component.try_borrow(&name)
+    }
+
+    /// Mutably borrow a single component of the entity with the given id. Returns the same errors
+    /// as `get`.
+    ///
+    /// ```
+    /// use bbecs::world::{World, WorldMethods};
+    ///
+    /// # fn main() -> eyre::Result<()> {
+    /// let mut world = World::new();
+    /// world.register("health")?;
+    /// world.spawn_entity()?.with_component("health", 100_u32)?;
+    ///
+    /// *world.get_mut::<u32>(0, "health")? -= 10;
+    /// assert_eq!(*world.get::<u32>(0, "health")?, 90);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_mut<T>(&self, entity_id: u32, key: impl ComponentKey) -> Result<RefMut<'_, T>>
+    where
+        ComponentData: CastComponents<T>,
+    {
+        let (component, name) = self.entity_component(entity_id, key)?;
+        component.try_borrow_mut(&name)
+    }
+
+    /// Find the component data for the entity with the given id, along with the name of the
+    /// component for error reporting
+    fn entity_component<K: ComponentKey>(
+        &self,
+        entity_id: u32,
+        key: K,
+    ) -> Result<(&ComponentData, String)> {
+        let id = self
+            .components
+            .resolve(&key, |name| BbEcsError::ComponentNotFound { name })?;
+        let name = key.describe(&self.components);
+        if self.components.is_tag(id) {
+            return Err(BbEcsError::TagHasNoData { name });
+        }
+        let entity_index = self.entity_index(entity_id)?;
+        let component_index = self
+            .bitmap
+            .component_index(id, entity_index)
+            .ok_or_else(|| BbEcsError::NoSuchComponent {
+                name: name.clone(),
+                entity_id,
+            })?;
+        let components = self
+            .entity_data
+            .get(id)
+            .ok_or_else(|| BbEcsError::ComponentNotFound { name: name.clone() })?;
+
+        Ok((&components[component_index], name))
+    }
+
+    /// Does an entity with the given id exist, this includes entities that will be deleted on the
+    /// next update
+    pub fn contains(&self, entity_id: u32) -> bool {
+        self.bitmap.entity_index(entity_id).is_some()
+    }
+
+    /// Find where the entity with the given id is stored in the bitmap, returning
+    /// `BbEcsError::NoSuchEntity` if there isn't an entity with that id
+    fn entity_index(&self, entity_id: u32) -> Result<usize> {
+        self.bitmap
+            .entity_index(entity_id)
+            .ok_or(BbEcsError::NoSuchEntity { entity_id })
+    }
+
     pub fn spawn_entity(&mut self) -> Result<&mut Self> {
-        self.entity_data.insert(TO_BE_DELETED, false)?;
-        self.entity_data.insert(ENTITY_ID, self.next_entity_id)?;
-        self.bitmap.spawn_entity();
-        self.bitmap.insert(TO_BE_DELETED)?;
-        self.bitmap.insert(ENTITY_ID)?;
-        self.is_empty = false;
+        self.entity_data
+            .insert(self.to_be_deleted_id, false, &self.components)?;
+        self.entity_data
+            .insert(self.entity_id_id, self.next_entity_id, &self.components)?;
+        self.bitmap.spawn_entity(self.next_entity_id);
+        self.bitmap
+            .insert(self.to_be_deleted_id, &self.components)?;
+        self.bitmap.insert(self.entity_id_id, &self.components)?;
         self.next_entity_id += 1;
 
         Ok(self)
     }
 
-    pub fn query(&self, names: Vec<&str>) -> Result<HashMap<String, Vec<&ComponentData>>> {
-        let bitmap_query = self.bitmap.query(names.clone())?;
-        self.entity_data.query(bitmap_query)
+    /// Query for every entity that has all of the given components. The components can be
+    /// identified by their `ComponentId`s or their names. Tags only filter the entities, so their
+    /// lists in the results are always empty.
+    pub fn query<I, K>(&self, keys: I) -> Result<QueryResults<'_>>
+    where
+        I: IntoIterator<Item = K>,
+        K: ComponentKey,
+    {
+        let ids = keys
+            .into_iter()
+            .map(|key| {
+                self.components
+                    .resolve(&key, |name| BbEcsError::BitMapComponentNotFound { name })
+            })
+            .collect::<Result<Vec<ComponentId>>>()?;
+        let tags: Vec<ComponentId> = ids
+            .iter()
+            .copied()
+            .filter(|id| self.components.is_tag(*id))
+            .collect();
+        let bitmap_query = self.bitmap.query(&ids, &self.components)?;
+        let components = self
+            .entity_data
+            .query(bitmap_query, &tags, &self.components)?;
+        Ok(QueryResults::new(&self.components, components))
     }
 
     pub fn get_resource<S: Into<String>>(&self, name: S) -> Result<&Rc<RefCell<Resource>>> {
         self.resources.get(&name.into())
     }
 
+    /// Immutably borrow a resource, returning `BbEcsError::BorrowConflict` if it is already
+    /// mutably borrowed
+    pub fn borrow_resource<S: Into<String>>(&self, name: S) -> Result<Ref<'_, Resource>> {
+        let name = name.into();
+        self.resources
+            .get(&name)?
+            .try_borrow()
+            .map_err(|_| BbEcsError::BorrowConflict { name })
+    }
+
+    /// Mutably borrow a resource, returning `BbEcsError::BorrowConflict` if it is already
+    /// borrowed
+    pub fn borrow_resource_mut<S: Into<String>>(&self, name: S) -> Result<RefMut<'_, Resource>> {
+        let name = name.into();
+        self.resources
+            .get(&name)?
+            .try_borrow_mut()
+            .map_err(|_| BbEcsError::BorrowConflict { name })
+    }
+
+    /// Restart the random number generator of the world with the given seed. The world is seeded
+    /// from the current time when it is created, seeding it with a known value instead makes the
+    /// whole session reproducible.
+    ///
+    /// ```
+    /// use bbecs::world::World;
+    ///
+    /// let mut world = World::new();
+    /// world.seed_rng(42);
+    /// let roll = world.rng_mut().range_u32(1, 7);
+    ///
+    /// world.seed_rng(42);
+    /// assert_eq!(world.rng_mut().range_u32(1, 7), roll);
+    /// assert_eq!(world.rng().seed(), 42);
+    /// ```
+    pub fn seed_rng(&mut self, seed: u64) {
+        self.rng.reseed(seed);
+    }
+
+    /// The random number generator of the world, for reading the seed it was started with
+    pub fn rng(&self) -> &Rng {
+        &self.rng
+    }
+
+    pub fn rng_mut(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+
+    /// Delete every entity that was marked to be deleted. Any `EntityRef` components or resources
+    /// that point at a deleted entity are cleared or flagged depending on their `OnDespawn`. If one
+    /// of them is already borrowed a `BbEcsError::BorrowConflict` is returned before anything is
+    /// deleted.
     pub fn update(&mut self) -> Result<()> {
-        let query_results = self.query(vec![TO_BE_DELETED])?;
-        let to_be_deleted_query = query_results.get(TO_BE_DELETED).unwrap();
+        let query_results = self.query(vec![self.to_be_deleted_id, self.entity_id_id])?;
+        let to_be_deleted_query = query_results.get(self.to_be_deleted_id).unwrap();
+        let entity_ids_query = query_results.get(self.entity_id_id).unwrap();
         let mut bitmap_indexes_to_delete = vec![];
+        let mut deleted_entity_ids = vec![];
 
-        to_be_deleted_query
-            .iter()
-            .enumerate()
-            .for_each(|(index, to_be_deleted)| {
-                let to_be_deleted: &Rc<RefCell<bool>> = to_be_deleted.cast().unwrap();
-                let to_be_deleted = to_be_deleted.borrow();
-                if *to_be_deleted {
-                    bitmap_indexes_to_delete.push(index);
-                }
-            });
+        for (index, to_be_deleted) in to_be_deleted_query.iter().enumerate() {
+            let to_be_deleted: Ref<bool> = to_be_deleted.try_borrow(TO_BE_DELETED)?;
+            if *to_be_deleted {
+                let entity_id: Ref<u32> = entity_ids_query[index].try_borrow(ENTITY_ID)?;
+                bitmap_indexes_to_delete.push(index);
+                deleted_entity_ids.push(*entity_id);
+            }
+        }
+
+        if deleted_entity_ids.is_empty() {
+            return Ok(());
+        }
+
+        // Borrow everything that needs to hear about the deleted entities before deleting them,
+        // a borrow conflict afterwards would leave the deletion half applied
+        let (entity_ref_components, listening_resources) = self.despawn_listeners();
+        let mut entity_refs = borrow_all_mut(&entity_ref_components)?;
+        let mut resources = borrow_all_mut(&listening_resources)?;
 
         let component_indexes_to_delete = self
             .bitmap
@@ -110,25 +532,219 @@ impl World {
 
         self.entity_data
             .delete_entities_by_index(component_indexes_to_delete)?;
+
+        for entity_ref in entity_refs.iter_mut() {
+            entity_ref.entities_despawned(&deleted_entity_ids);
+        }
+        for resource in resources.iter_mut() {
+            notify_despawned(resource, &deleted_entity_ids);
+        }
+
         Ok(())
     }
 
-    pub fn delete_by_id(&self, id: u32) -> Result<()> {
-        let query_results = self.query(vec![TO_BE_DELETED, ENTITY_ID])?;
-        let query_to_be_deleted = query_results.get(TO_BE_DELETED).unwrap();
-        let query_ids = query_results.get(ENTITY_ID).unwrap();
-
-        for (index, component_id) in query_ids.iter().enumerate() {
-            let wrapped_component_id: &Rc<RefCell<u32>> = component_id.cast()?;
-            let component_id = wrapped_component_id.borrow();
-
-            if *component_id == id {
-                let wrapped_to_be_deleted: &Rc<RefCell<bool>> =
-                    query_to_be_deleted[index].cast()?;
-                let mut to_be_deleted = wrapped_to_be_deleted.borrow_mut();
-                *to_be_deleted = true;
+    /// Advance every `Timer` component by the f32 delta time stored in the named resource. Systems
+    /// can then check `Timer::just_finished` to react on the frame that a timer completes.
+    ///
+    /// ```
+    /// use bbecs::data_types::timer::Timer;
+    /// use bbecs::world::{World, WorldMethods};
+    ///
+    /// # fn main() -> eyre::Result<()> {
+    /// let mut world = World::new();
+    /// world.register("cooldown")?;
+    /// world.spawn_entity()?.with_component("cooldown", Timer::once(0.5))?;
+    /// world.add_resource("delta time".to_owned(), 0.5_f32);
+    ///
+    /// world.tick_timers("delta time")?;
+    /// assert!(world.get::<Timer>(0, "cooldown")?.just_finished());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tick_timers<S: Into<String>>(&self, delta_time_resource: S) -> Result<()> {
+        let delta_time = {
+            let delta_time_resource: String = delta_time_resource.into();
+            let resource = self.borrow_resource(delta_time_resource.as_str())?;
+            let delta_time: &f32 = resource
+                .cast()
+                .map_err(|error| error.with_name(&delta_time_resource))?;
+            *delta_time
+        };
+
+        for id in self.bitmap.registered() {
+            for component in self.entity_data.get(id).into_iter().flatten() {
+                if let ComponentData::Timer(timer) = component {
+                    timer
+                        .try_borrow_mut()
+                        .map_err(|_| BbEcsError::BorrowConflict {
+                            name: id.describe(&self.components),
+                        })?
+                        .tick(delta_time);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rebuild the `SpatialHash` stored in the named resource from the locations stored in the
+    /// given `Point` component, so that it can answer neighbor queries for this frame
+    ///
+    /// ```
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::spatial_hash::SpatialHash;
+    /// use bbecs::resources::resource::ResourceCast;
+    /// use bbecs::world::{World, WorldMethods};
+    ///
+    /// # fn main() -> eyre::Result<()> {
+    /// let mut world = World::new();
+    /// world.register("location")?;
+    /// world.spawn_entity()?.with_component("location", Point::new(5.0, 5.0))?;
+    /// world.add_resource("spatial hash".to_owned(), SpatialHash::new(10.0));
+    ///
+    /// world.update_spatial_hash("spatial hash", "location")?;
+    /// let spatial_hash = world.borrow_resource("spatial hash")?;
+    /// let spatial_hash: &SpatialHash = spatial_hash.cast()?;
+    /// assert_eq!(spatial_hash.query_radius(&Point::new(0.0, 0.0), 10.0), vec![0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_spatial_hash<S: Into<String>>(
+        &self,
+        spatial_hash_resource: S,
+        key: impl ComponentKey,
+    ) -> Result<()> {
+        let location = self.resolve_component(&key)?;
+        self.rebuild_spatial_hash(spatial_hash_resource.into(), location, None)
+    }
+
+    /// Rebuild the `SpatialHash` stored in the named resource from the entities that have both the
+    /// location and the `Collider` component, putting each entity in the layers of its collider so
+    /// that masked queries skip entities that couldn't collide
+    ///
+    /// ```
+    /// use bbecs::data_types::collider::Collider;
+    /// use bbecs::data_types::collision_layers::CollisionLayers;
+    /// use bbecs::data_types::point::Point;
+    /// use bbecs::data_types::spatial_hash::SpatialHash;
+    /// use bbecs::resources::resource::ResourceCast;
+    /// use bbecs::world::{World, WorldMethods};
+    ///
+    /// # fn main() -> eyre::Result<()> {
+    /// const PICKUP: CollisionLayers = CollisionLayers::layer(3);
+    /// let mut world = World::new();
+    /// world.register("location")?;
+    /// world.register("collider")?;
+    /// world
+    ///     .spawn_entity()?
+    ///     .with_component("location", Point::new(5.0, 5.0))?
+    ///     .with_component("collider", Collider::circle(2.0).with_layers(PICKUP))?;
+    /// world.add_resource("spatial hash".to_owned(), SpatialHash::new(10.0));
+    ///
+    /// world.update_spatial_hash_with_colliders("spatial hash", "location", "collider")?;
+    /// let spatial_hash = world.borrow_resource("spatial hash")?;
+    /// let spatial_hash: &SpatialHash = spatial_hash.cast()?;
+    /// let location = Point::new(0.0, 0.0);
+    /// assert_eq!(spatial_hash.query_radius_masked(&location, 10.0, PICKUP), vec![0]);
+    /// assert!(spatial_hash
+    ///     .query_radius_masked(&location, 10.0, CollisionLayers::DEFAULT)
+    ///     .is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_spatial_hash_with_colliders<S: Into<String>>(
+        &self,
+        spatial_hash_resource: S,
+        location_key: impl ComponentKey,
+        collider_key: impl ComponentKey,
+    ) -> Result<()> {
+        let location = self.resolve_component(&location_key)?;
+        let collider = self.resolve_component(&collider_key)?;
+        self.rebuild_spatial_hash(spatial_hash_resource.into(), location, Some(collider))
+    }
+
+    /// The id and name of a registered component
+    fn resolve_component<K: ComponentKey>(&self, key: &K) -> Result<(ComponentId, String)> {
+        let id = self
+            .components
+            .resolve(key, |name| BbEcsError::ComponentNotFound { name })?;
+        Ok((id, key.describe(&self.components)))
+    }
+
+    /// Clear the spatial hash and insert every entity with the location component. With a
+    /// collider component only the entities that also have a collider are inserted, in the layers
+    /// of their collider, otherwise the entities are inserted in the default layer.
+    fn rebuild_spatial_hash(
+        &self,
+        spatial_hash_resource: String,
+        (location_id, location_name): (ComponentId, String),
+        collider: Option<(ComponentId, String)>,
+    ) -> Result<()> {
+        let mut keys = vec![location_id, self.entity_id_id];
+        keys.extend(collider.as_ref().map(|(collider_id, _)| *collider_id));
+        let query_results = self.query(keys)?;
+        let locations = query_results.get(location_id).unwrap();
+        let entity_ids = query_results.get(self.entity_id_id).unwrap();
+        let colliders = collider.as_ref().map(|(collider_id, collider_name)| {
+            (query_results.get(*collider_id).unwrap(), collider_name)
+        });
+
+        let mut resource = self.borrow_resource_mut(spatial_hash_resource.as_str())?;
+        let spatial_hash: &mut SpatialHash = resource
+            .cast_mut()
+            .map_err(|error| error.with_name(&spatial_hash_resource))?;
+        spatial_hash.clear();
+
+        for (index, location) in locations.iter().enumerate() {
+            let location: Ref<Point> = location.try_borrow(&location_name)?;
+            let entity_id: Ref<u32> = entity_ids[index].try_borrow(ENTITY_ID)?;
+            let layers = match colliders {
+                Some((colliders, collider_name)) => {
+                    let collider: Ref<Collider> = colliders[index].try_borrow(collider_name)?;
+                    collider.layers
+                }
+                None => CollisionLayers::DEFAULT,
+            };
+            spatial_hash.insert_in_layers(*entity_id, *location, layers);
+        }
+
+        Ok(())
+    }
+
+    /// Everything that has to be told when entities are despawned: every `EntityRef` component and
+    /// every `EntityRef` or `SpatialHash` resource, along with their names for error reporting.
+    /// Resources aren't borrowed to check their type so that unrelated resources that are borrowed
+    /// elsewhere don't get in the way.
+    #[allow(clippy::type_complexity)]
+    fn despawn_listeners(
+        &self,
+    ) -> (
+        Vec<(String, Rc<RefCell<EntityRef>>)>,
+        Vec<(String, Rc<RefCell<Resource>>)>,
+    ) {
+        let mut components = vec![];
+        for id in self.bitmap.registered() {
+            for component in self.entity_data.get(id).into_iter().flatten() {
+                if let ComponentData::EntityRef(entity_ref) = component {
+                    components.push((id.describe(&self.components), Rc::clone(entity_ref)));
+                }
             }
         }
+
+        let resources = self
+            .resources
+            .iter()
+            .filter(|(name, _)| self.resources.tracks_entities(name))
+            .map(|(name, resource)| (name.clone(), Rc::clone(resource)))
+            .collect();
+
+        (components, resources)
+    }
+
+    /// Mark the entity with the given id to be deleted on the next update. Returns
+    /// `BbEcsError::NoSuchEntity` if no entity has that id.
+    pub fn delete_by_id(&self, id: u32) -> Result<()> {
+        *self.get_mut::<bool>(id, self.to_be_deleted_id)? = true;
         Ok(())
     }
 }
@@ -137,31 +753,83 @@ impl Default for World {
     fn default() -> Self {
         let mut entity_data = EntityData::new();
         let mut bitmap = BitMap::new();
+        let mut components = ComponentRegistry::new();
 
-        entity_data.register(TO_BE_DELETED.into()).unwrap();
-        bitmap.register(TO_BE_DELETED.into());
-        entity_data.register(ENTITY_ID.into()).unwrap();
-        bitmap.register(ENTITY_ID.into());
+        let to_be_deleted_id = components.register(TO_BE_DELETED.into()).unwrap();
+        entity_data.register();
+        bitmap.register();
+        let entity_id_id = components.register(ENTITY_ID.into()).unwrap();
+        entity_data.register();
+        bitmap.register();
 
         Self {
             entity_data,
             resources: ResourcesData::new(),
-            is_empty: true,
             next_entity_id: 0,
             bitmap,
+            components,
+            to_be_deleted_id,
+            entity_id_id,
+            rng: Rng::from_time(),
         }
     }
 }
 
-impl_world_trait!(Color, Color);
-impl_world_trait!(Mesh, Mesh);
 impl_world_trait!(Point, Point);
+impl_world_trait!(Color, Color);
 impl_world_trait!(u32, U32);
 impl_world_trait!(f32, F32);
 impl_world_trait!(usize, Usize);
 impl_world_trait!(bool, Bool);
-impl_world_trait!(KeyCode, GgezKeyCode);
 impl_world_trait!(String, Marker);
+impl_world_trait!(EntityRef, EntityRef);
+impl_world_trait!(Vec<Point>, PointList);
+impl_world_trait!(Vec<f32>, F32List);
+impl_world_trait!(Timer, Timer);
+impl_world_trait!(Rect, Rect);
+impl_world_trait!(Circle, Circle);
+impl_world_trait!(Transform, Transform);
+impl_world_resource!(SpatialHash, SpatialHash);
+impl_world_trait!(Collider, Collider);
+impl_world_trait!(Boundary, Boundary);
+
+#[cfg(feature = "ggez")]
+impl_world_trait!(GgezColor, GgezColor);
+#[cfg(feature = "ggez")]
+impl_world_trait!(Mesh, Mesh);
+#[cfg(feature = "ggez")]
+impl_world_trait!(KeyCode, GgezKeyCode);
+#[cfg(feature = "ggez")]
 impl_world_trait!(Text, GgezText);
+#[cfg(feature = "ggez")]
 impl_world_trait!(SoundData, GgezSound);
+#[cfg(feature = "ggez")]
 impl_world_trait!(TextFragment, GgezTextFragment);
+
+/// Mutably borrow every named value, returning `BbEcsError::BorrowConflict` with the name of the
+/// first one that is already borrowed
+fn borrow_all_mut<T>(values: &[(String, Rc<RefCell<T>>)]) -> Result<Vec<RefMut<'_, T>>> {
+    values
+        .iter()
+        .map(|(name, value)| {
+            value
+                .try_borrow_mut()
+                .map_err(|_| BbEcsError::BorrowConflict { name: name.clone() })
+        })
+        .collect()
+}
+
+/// Let a resource that keeps track of entities know which entities were deleted. Entity references
+/// are cleared or flagged and the entities are pruned from spatial hashes, other resources are left
+/// alone.
+fn notify_despawned(resource: &mut Resource, deleted_entity_ids: &[u32]) {
+    match resource {
+        Resource::EntityRef(entity_ref) => entity_ref.entities_despawned(deleted_entity_ids),
+        Resource::SpatialHash(spatial_hash) => {
+            for entity_id in deleted_entity_ids {
+                spatial_hash.remove(*entity_id);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,48 @@
+use crate::components::ComponentData;
+use crate::errors::{BbEcsError, Result};
+
+use super::component_id::{ComponentId, ComponentKey, ComponentRegistry};
+
+/// The components returned from `World::query`. Each queried component can be retrieved by its
+/// `ComponentId` or its name, and the lists are in the same entity order so that the same index
+/// refers to the same entity across all of them.
+#[derive(Debug)]
+pub struct QueryResults<'a> {
+    registry: &'a ComponentRegistry,
+    components: Vec<(ComponentId, Vec<&'a ComponentData>)>,
+}
+
+impl<'a> QueryResults<'a> {
+    pub fn new(
+        registry: &'a ComponentRegistry,
+        components: Vec<(ComponentId, Vec<&'a ComponentData>)>,
+    ) -> Self {
+        Self {
+            registry,
+            components,
+        }
+    }
+
+    /// Get the queried components by id or name, returns None if the component wasn't part of the
+    /// query
+    pub fn get<K: ComponentKey>(&self, key: K) -> Option<&Vec<&'a ComponentData>> {
+        self.find(key.lookup(self.registry)?)
+    }
+
+    /// Get the queried components by id or name, returning `BbEcsError::ComponentNotFound` if the
+    /// component wasn't part of the query
+    pub fn try_get<K: ComponentKey>(&self, key: K) -> Result<&Vec<&'a ComponentData>> {
+        key.lookup(self.registry)
+            .and_then(|id| self.find(id))
+            .ok_or_else(|| BbEcsError::ComponentNotFound {
+                name: key.describe(self.registry),
+            })
+    }
+
+    fn find(&self, id: ComponentId) -> Option<&Vec<&'a ComponentData>> {
+        self.components
+            .iter()
+            .find(|(component_id, _)| *component_id == id)
+            .map(|(_, components)| components)
+    }
+}
@@ -0,0 +1,79 @@
+use std::cell::{Ref, RefMut};
+
+use bbecs::components::CastComponents;
+use bbecs::errors::BbEcsError;
+use bbecs::world::{World, WorldMethods, TO_BE_DELETED};
+use eyre::Result;
+
+#[test]
+fn borrowing_a_component_mutably_twice_returns_a_conflict() -> Result<()> {
+    let mut world = World::new();
+    world.register("size")?;
+    world.spawn_entity()?.with_component("size", 15.0_f32)?;
+
+    let query = world.query(vec!["size"])?;
+    let sizes = query.get("size").unwrap();
+    let _size: RefMut<f32> = sizes[0].try_borrow_mut("size")?;
+    let result: bbecs::errors::Result<Ref<f32>> = sizes[0].try_borrow("size");
+
+    match result {
+        Err(BbEcsError::BorrowConflict { name }) => assert_eq!(name, "size"),
+        _ => panic!("expected a BorrowConflict error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn deleting_while_holding_a_to_be_deleted_borrow_returns_a_conflict() -> Result<()> {
+    let mut world = World::new();
+    world.spawn_entity()?;
+
+    let query = world.query(vec![TO_BE_DELETED])?;
+    let _to_be_deleted: Ref<bool> =
+        query.get(TO_BE_DELETED).unwrap()[0].try_borrow(TO_BE_DELETED)?;
+
+    match world.delete_by_id(0) {
+        Err(BbEcsError::BorrowConflict { name }) => assert_eq!(name, TO_BE_DELETED),
+        _ => panic!("expected a BorrowConflict error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn borrowing_a_resource_mutably_twice_returns_a_conflict() -> Result<()> {
+    let mut world = World::new();
+    world.add_resource("size".to_owned(), 15.0_f32);
+
+    let _size = world.borrow_resource_mut("size")?;
+
+    match world.borrow_resource_mut("size") {
+        Err(BbEcsError::BorrowConflict { name }) => assert_eq!(name, "size"),
+        _ => panic!("expected a BorrowConflict error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn get_resource_macros_return_borrow_conflicts() -> Result<()> {
+    use bbecs::resources::resource::ResourceCast;
+    use bbecs::{get_resource, get_resource_mut};
+
+    fn read_size(world: &World) -> bbecs::errors::Result<f32> {
+        let size: &f32;
+        get_resource!(size, world, "size");
+        Ok(*size)
+    }
+
+    let mut world = World::new();
+    world.add_resource("size".to_owned(), 15.0_f32);
+
+    let size: &mut f32;
+    get_resource_mut!(size, world, "size");
+    *size += 1.0;
+
+    assert!(matches!(
+        read_size(&world),
+        Err(BbEcsError::BorrowConflict { .. })
+    ));
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use bbecs::boundary::BoundarySystem;
+use bbecs::data_types::boundary::Boundary;
+use bbecs::data_types::point::Point;
+use bbecs::data_types::rect::Rect;
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+fn spawn(world: &mut World, location: Point, velocity: Point, boundary: Boundary) -> Result<()> {
+    world
+        .spawn_entity()?
+        .with_component("location", location)?
+        .with_component("velocity", velocity)?
+        .with_component("boundary", boundary)?;
+    Ok(())
+}
+
+#[test]
+fn wrapping_once_fully_off_screen() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("boundary")?;
+    world.add_resource(
+        "arena".to_owned(),
+        Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+    );
+    spawn(
+        &mut world,
+        Point::new(-5.0, 300.0),
+        Point::new(-1.0, 0.0),
+        Boundary::wrap(10.0),
+    )?;
+    spawn(
+        &mut world,
+        Point::new(400.0, -15.0),
+        Point::new(0.0, -1.0),
+        Boundary::wrap(10.0),
+    )?;
+
+    BoundarySystem::new().run(&world)?;
+
+    assert_eq!(*world.get::<Point>(0, "location")?, Point::new(-5.0, 300.0));
+    assert_eq!(
+        *world.get::<Point>(1, "location")?,
+        Point::new(400.0, 605.0)
+    );
+    assert_eq!(*world.get::<Point>(1, "velocity")?, Point::new(0.0, -1.0));
+    Ok(())
+}
+
+#[test]
+fn bouncing_off_of_the_walls() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("boundary")?;
+    world.add_resource(
+        "arena".to_owned(),
+        Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+    );
+    spawn(
+        &mut world,
+        Point::new(795.0, 2.0),
+        Point::new(3.0, -4.0),
+        Boundary::bounce(10.0),
+    )?;
+
+    BoundarySystem::new().run(&world)?;
+
+    assert_eq!(*world.get::<Point>(0, "location")?, Point::new(790.0, 10.0));
+    assert_eq!(*world.get::<Point>(0, "velocity")?, Point::new(-3.0, 4.0));
+    Ok(())
+}
+
+#[test]
+fn despawning_off_screen_entities() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("boundary")?;
+    world.add_resource(
+        "arena".to_owned(),
+        Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+    );
+    spawn(
+        &mut world,
+        Point::new(805.0, 300.0),
+        Point::new(1.0, 0.0),
+        Boundary::despawn(10.0),
+    )?;
+    spawn(
+        &mut world,
+        Point::new(815.0, 300.0),
+        Point::new(1.0, 0.0),
+        Boundary::despawn(10.0),
+    )?;
+
+    BoundarySystem::new().run(&world)?;
+    world.update()?;
+
+    assert!(world.contains(0));
+    assert!(!world.contains(1));
+    Ok(())
+}
+
+#[test]
+fn running_without_an_arena_is_an_error() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("boundary")?;
+    world.add_resource(
+        "arena".to_owned(),
+        Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+    );
+    world.clear_resources();
+
+    assert!(BoundarySystem::new().run(&world).is_err());
+    Ok(())
+}
+
+#[test]
+fn wrapping_entities_without_a_velocity() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("boundary")?;
+    world.add_resource(
+        "arena".to_owned(),
+        Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+    );
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(820.0, 300.0))?
+        .with_component("boundary", Boundary::wrap(10.0))?;
+
+    BoundarySystem::new().run(&world)?;
+
+    assert_eq!(*world.get::<Point>(0, "location")?, Point::new(0.0, 300.0));
+    Ok(())
+}
+
+#[test]
+fn bouncing_entities_without_a_velocity_are_kept_inside() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("boundary")?;
+    world.add_resource(
+        "arena".to_owned(),
+        Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+    );
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(-5.0, 300.0))?
+        .with_component("boundary", Boundary::bounce(0.0))?;
+
+    BoundarySystem::new().run(&world)?;
+
+    assert_eq!(*world.get::<Point>(0, "location")?, Point::new(0.0, 300.0));
+    Ok(())
+}
+
+#[test]
+fn bouncing_without_a_registered_velocity_component() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("boundary")?;
+    world.add_resource(
+        "arena".to_owned(),
+        Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+    );
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(805.0, 300.0))?
+        .with_component("boundary", Boundary::bounce(0.0))?;
+
+    BoundarySystem::new().run(&world)?;
+
+    assert_eq!(
+        *world.get::<Point>(0, "location")?,
+        Point::new(800.0, 300.0)
+    );
+    Ok(())
+}
+
+#[test]
+fn wrapping_inside_an_arena_without_any_size() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("boundary")?;
+    world.add_resource(
+        "arena".to_owned(),
+        Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+    );
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(900.0, 700.0))?
+        .with_component("boundary", Boundary::wrap(-300.0))?;
+
+    BoundarySystem::new().run(&world)?;
+
+    let location = *world.get::<Point>(0, "location")?;
+    assert!(!location.x.is_nan() && !location.y.is_nan());
+    assert_eq!(location, Point::new(300.0, 300.0));
+    Ok(())
+}
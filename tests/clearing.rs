@@ -0,0 +1,173 @@
+use std::cell::Ref;
+
+use bbecs::components::CastComponents;
+use bbecs::data_types::entity_ref::{EntityRef, OnDespawn};
+use bbecs::data_types::point::Point;
+use bbecs::errors::BbEcsError;
+use bbecs::resources::resource::ResourceCast;
+use bbecs::world::{World, WorldMethods, ENTITY_ID};
+use eyre::Result;
+
+fn spawn_two_entities(world: &mut World) -> Result<()> {
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("size", 15.0_f32)?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?;
+    Ok(())
+}
+
+#[test]
+fn clearing_entities_keeps_registrations_and_resources() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("size")?;
+    world.add_resource("score".to_owned(), 10_u32);
+    spawn_two_entities(&mut world)?;
+
+    world.clear_entities(false)?;
+
+    assert_eq!(
+        world
+            .query(vec!["location"])?
+            .get("location")
+            .unwrap()
+            .len(),
+        0
+    );
+    assert!(world.get_resource("score").is_ok());
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(5.0, 5.0))?;
+    let query = world.query(vec!["location", ENTITY_ID])?;
+    let id: Ref<u32> = query.get(ENTITY_ID).unwrap()[0].try_borrow(ENTITY_ID)?;
+    assert_eq!(*id, 2);
+    Ok(())
+}
+
+#[test]
+fn clearing_entities_can_reset_entity_ids() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("size")?;
+    spawn_two_entities(&mut world)?;
+
+    world.clear_entities(true)?;
+    world.spawn_entity()?;
+
+    let query = world.query(vec![ENTITY_ID])?;
+    let ids = query.get(ENTITY_ID).unwrap();
+    let id: Ref<u32> = ids[0].try_borrow(ENTITY_ID)?;
+    assert_eq!(ids.len(), 1);
+    assert_eq!(*id, 0);
+    Ok(())
+}
+
+#[test]
+fn unregistering_a_component_removes_its_data() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    let size_id = world.register("size")?;
+    spawn_two_entities(&mut world)?;
+
+    world.unregister("size")?;
+
+    assert!(matches!(
+        world.query(vec!["size"]),
+        Err(BbEcsError::BitMapComponentNotFound { .. })
+    ));
+    assert!(matches!(
+        world.query(vec![size_id]),
+        Err(BbEcsError::BitMapComponentNotFound { .. })
+    ));
+    assert_eq!(
+        world
+            .query(vec!["location"])?
+            .get("location")
+            .unwrap()
+            .len(),
+        2
+    );
+
+    let new_size_id = world.register("size")?;
+    assert_ne!(size_id, new_size_id);
+    world.spawn_entity()?.with_component("size", 30.0_f32)?;
+    assert_eq!(world.query(vec!["size"])?.get("size").unwrap().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn deleting_entities_after_unregistering_a_component() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("size")?;
+    spawn_two_entities(&mut world)?;
+
+    world.unregister("size")?;
+    world.delete_by_id(0)?;
+    world.update()?;
+
+    let query = world.query(vec!["location"])?;
+    let location: Ref<Point> = query.get("location").unwrap()[0].try_borrow("location")?;
+    assert_eq!(*location, Point::new(10.0, 10.0));
+    Ok(())
+}
+
+#[test]
+fn built_in_components_cannot_be_unregistered() {
+    let mut world = World::new();
+
+    assert!(matches!(
+        world.unregister(ENTITY_ID),
+        Err(BbEcsError::CannotUnregisterBuiltIn { .. })
+    ));
+}
+
+#[test]
+fn clearing_resources_keeps_entities() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("size")?;
+    world.add_resource("score".to_owned(), 10_u32);
+    spawn_two_entities(&mut world)?;
+
+    world.clear_resources();
+
+    assert!(matches!(
+        world.get_resource("score"),
+        Err(BbEcsError::ResourceNotFound { .. })
+    ));
+    assert_eq!(
+        world
+            .query(vec!["location"])?
+            .get("location")
+            .unwrap()
+            .len(),
+        2
+    );
+    Ok(())
+}
+
+#[test]
+fn clearing_entities_clears_entity_ref_resources() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("size")?;
+    spawn_two_entities(&mut world)?;
+    world.add_resource(
+        "camera target".to_owned(),
+        EntityRef::new(1, OnDespawn::Clear),
+    );
+
+    world.clear_entities(true)?;
+    world.spawn_entity()?;
+    world.spawn_entity()?;
+
+    let camera_target = world.borrow_resource("camera target")?;
+    let camera_target: &EntityRef = camera_target.cast()?;
+    assert_eq!(camera_target.get(), None);
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use bbecs::collision::response::CollisionResponseSystem;
+use bbecs::data_types::collider::Collider;
+use bbecs::data_types::point::Point;
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+fn spawn_ball(
+    world: &mut World,
+    location: Point,
+    velocity: Point,
+    collider: Collider,
+    mass: f32,
+    restitution: f32,
+) -> Result<()> {
+    world
+        .spawn_entity()?
+        .with_component("location", location)?
+        .with_component("velocity", velocity)?
+        .with_component("collider", collider)?
+        .with_component("mass", mass)?
+        .with_component("restitution", restitution)?;
+    Ok(())
+}
+
+fn close_to(point: Point, expected: Point) -> bool {
+    point.distance_to(&expected) < 0.0001
+}
+
+#[test]
+fn immovable_walls_bounce_balls() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("collider")?;
+    world.register("mass")?;
+    world.register("restitution")?;
+    let ball = Collider::circle(5.0);
+    let wall = Collider::circle(50.0);
+    spawn_ball(
+        &mut world,
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 4.0),
+        ball,
+        1.0,
+        0.5,
+    )?;
+    spawn_ball(
+        &mut world,
+        Point::new(0.0, 53.0),
+        Point::new(0.0, 0.0),
+        wall,
+        0.0,
+        1.0,
+    )?;
+
+    CollisionResponseSystem::new().run(&world)?;
+
+    assert!(close_to(
+        *world.get::<Point>(0, "location")?,
+        Point::new(0.0, -2.0)
+    ));
+    assert!(close_to(
+        *world.get::<Point>(0, "velocity")?,
+        Point::new(0.0, -2.0)
+    ));
+    assert_eq!(*world.get::<Point>(1, "location")?, Point::new(0.0, 53.0));
+    assert_eq!(*world.get::<Point>(1, "velocity")?, Point::new(0.0, 0.0));
+    Ok(())
+}
+
+#[test]
+fn heavier_bodies_push_lighter_bodies() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("collider")?;
+    world.register("mass")?;
+    world.register("restitution")?;
+    let collider = Collider::circle(5.0);
+    spawn_ball(
+        &mut world,
+        Point::new(0.0, 0.0),
+        Point::new(3.0, 0.0),
+        collider,
+        3.0,
+        0.0,
+    )?;
+    spawn_ball(
+        &mut world,
+        Point::new(6.0, 0.0),
+        Point::new(0.0, 0.0),
+        collider,
+        1.0,
+        0.0,
+    )?;
+
+    CollisionResponseSystem::new().run(&world)?;
+
+    // the penetration of 4 is split by inverse mass, and without restitution both end up moving
+    // together while keeping the total momentum
+    assert!(close_to(
+        *world.get::<Point>(0, "location")?,
+        Point::new(-1.0, 0.0)
+    ));
+    assert!(close_to(
+        *world.get::<Point>(1, "location")?,
+        Point::new(9.0, 0.0)
+    ));
+    assert!(close_to(
+        *world.get::<Point>(0, "velocity")?,
+        Point::new(2.25, 0.0)
+    ));
+    assert!(close_to(
+        *world.get::<Point>(1, "velocity")?,
+        Point::new(2.25, 0.0)
+    ));
+    Ok(())
+}
+
+#[test]
+fn separating_bodies_are_pushed_apart_without_an_impulse() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("collider")?;
+    world.register("mass")?;
+    world.register("restitution")?;
+    let collider = Collider::circle(5.0);
+    spawn_ball(
+        &mut world,
+        Point::new(0.0, 0.0),
+        Point::new(-1.0, 0.0),
+        collider,
+        1.0,
+        1.0,
+    )?;
+    spawn_ball(
+        &mut world,
+        Point::new(8.0, 0.0),
+        Point::new(1.0, 0.0),
+        collider,
+        1.0,
+        1.0,
+    )?;
+
+    CollisionResponseSystem::new().run(&world)?;
+
+    assert_eq!(*world.get::<Point>(0, "velocity")?, Point::new(-1.0, 0.0));
+    assert_eq!(*world.get::<Point>(1, "velocity")?, Point::new(1.0, 0.0));
+    assert_eq!(*world.get::<Point>(1, "location")?, Point::new(9.0, 0.0));
+    Ok(())
+}
+
+#[test]
+fn rect_colliders_are_ignored() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("collider")?;
+    world.register("mass")?;
+    world.register("restitution")?;
+    let circle = Collider::circle(5.0);
+    let rect = Collider::rect(Point::new(10.0, 10.0));
+    spawn_ball(
+        &mut world,
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        circle,
+        1.0,
+        1.0,
+    )?;
+    spawn_ball(
+        &mut world,
+        Point::new(4.0, 0.0),
+        Point::new(0.0, 0.0),
+        rect,
+        1.0,
+        1.0,
+    )?;
+
+    CollisionResponseSystem::new().run(&world)?;
+
+    assert_eq!(*world.get::<Point>(0, "location")?, Point::new(0.0, 0.0));
+    assert_eq!(*world.get::<Point>(0, "velocity")?, Point::new(1.0, 0.0));
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use bbecs::collision::{CollisionEvent, CollisionSystem};
+use bbecs::data_types::collider::Collider;
+use bbecs::data_types::collision_layers::CollisionLayers;
+use bbecs::data_types::point::Point;
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+#[test]
+fn collisions_start_and_end_as_entities_move() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("collider")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("collider", Collider::circle(5.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(20.0, 0.0))?
+        .with_component("collider", Collider::rect(Point::new(10.0, 10.0)))?;
+    let mut collision_system = CollisionSystem::new();
+
+    assert!(collision_system.run(&world)?.is_empty());
+
+    *world.get_mut::<Point>(1, "location")? = Point::new(8.0, 0.0);
+    assert_eq!(
+        collision_system.run(&world)?,
+        vec![CollisionEvent::Started {
+            first: 0,
+            second: 1
+        }]
+    );
+    assert!(collision_system.is_colliding(1, 0));
+
+    *world.get_mut::<Point>(1, "location")? = Point::new(8.0, 100.0);
+    assert_eq!(
+        collision_system.run(&world)?,
+        vec![CollisionEvent::Ended {
+            first: 0,
+            second: 1
+        }]
+    );
+    assert!(!collision_system.is_colliding(0, 1));
+    Ok(())
+}
+
+#[test]
+fn deleting_a_colliding_entity_ends_its_collisions() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("collider")?;
+    for _ in 0..3 {
+        world
+            .spawn_entity()?
+            .with_component("location", Point::new(0.0, 0.0))?
+            .with_component("collider", Collider::circle(1.0))?;
+    }
+    let mut collision_system = CollisionSystem::new();
+    assert_eq!(collision_system.run(&world)?.len(), 3);
+
+    world.delete_by_id(1)?;
+    world.update()?;
+
+    let events = collision_system.run(&world)?;
+    assert_eq!(events.len(), 2);
+    assert!(events
+        .iter()
+        .all(|event| matches!(event, CollisionEvent::Ended { .. }) && event.involves(1)));
+    assert_eq!(
+        collision_system.collisions().collect::<Vec<_>>(),
+        vec![(0, 2)]
+    );
+    Ok(())
+}
+
+#[test]
+fn entities_without_a_location_do_not_collide() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("collider")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("collider", Collider::circle(5.0))?;
+    world
+        .spawn_entity()?
+        .with_component("collider", Collider::circle(5.0))?;
+
+    assert!(CollisionSystem::new().run(&world)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn many_entities_in_a_row_only_collide_with_their_neighbors() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("collider")?;
+    for index in 0..50 {
+        world
+            .spawn_entity()?
+            .with_component("location", Point::new(index as f32 * 15.0, 0.0))?
+            .with_component("collider", Collider::rect(Point::new(20.0, 20.0)))?;
+    }
+
+    let events = CollisionSystem::new().run(&world)?;
+
+    assert_eq!(events.len(), 49);
+    assert!(events
+        .iter()
+        .all(|event| event.entity_ids().1 == event.entity_ids().0 + 1));
+    Ok(())
+}
+
+#[test]
+fn layers_and_masks_filter_collisions() -> Result<()> {
+    const PLAYER_TEAM: CollisionLayers = CollisionLayers::layer(1);
+    const ENEMY_TEAM: CollisionLayers = CollisionLayers::layer(2);
+    const PICKUP: CollisionLayers = CollisionLayers::layer(3);
+
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("collider")?;
+    let colliders = [
+        Collider::circle(10.0).with_layers(PLAYER_TEAM),
+        Collider::circle(1.0)
+            .with_layers(PLAYER_TEAM)
+            .with_mask(ENEMY_TEAM),
+        Collider::circle(10.0).with_layers(ENEMY_TEAM),
+        Collider::circle(5.0)
+            .with_layers(PICKUP)
+            .with_mask(PLAYER_TEAM),
+    ];
+    for collider in colliders.iter() {
+        world
+            .spawn_entity()?
+            .with_component("location", Point::new(0.0, 0.0))?
+            .with_component("collider", *collider)?;
+    }
+
+    let mut collision_system = CollisionSystem::new();
+    collision_system.run(&world)?;
+
+    assert_eq!(
+        collision_system.collisions().collect::<Vec<_>>(),
+        vec![(0, 2), (0, 3), (1, 2)]
+    );
+    Ok(())
+}
+
+#[test]
+fn the_last_layer_is_the_highest_bit() {
+    assert_eq!(CollisionLayers::layer(31).bits(), 1 << 31);
+}
+
+#[test]
+#[should_panic]
+fn there_are_only_32_layers() {
+    CollisionLayers::layer(32);
+}
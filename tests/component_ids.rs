@@ -0,0 +1,89 @@
+use std::cell::Ref;
+
+use bbecs::components::CastComponents;
+use bbecs::data_types::point::Point;
+use bbecs::errors::BbEcsError;
+use bbecs::world::bitmap::BitMap;
+use bbecs::world::component_id::ComponentRegistry;
+use bbecs::world::{World, WorldMethods, ENTITY_ID};
+use eyre::Result;
+
+#[test]
+fn inserting_and_querying_with_component_ids() -> Result<()> {
+    let mut world = World::new();
+    let location_id = world.register("location")?;
+    let size_id = world.register("size")?;
+
+    world
+        .spawn_entity()?
+        .with_component(location_id, Point::new(1.0, 2.0))?
+        .with_component(size_id, 15.0_f32)?;
+    world
+        .spawn_entity()?
+        .with_component(location_id, Point::new(3.0, 4.0))?;
+
+    let query = world.query(vec![location_id, size_id])?;
+    let locations = query.get(location_id).unwrap();
+    let sizes = query.get("size").unwrap();
+
+    assert_eq!(locations.len(), 1);
+    let location: Ref<Point> = locations[0].try_borrow("location")?;
+    let size: Ref<f32> = sizes[0].try_borrow("size")?;
+    assert_eq!(*location, Point::new(1.0, 2.0));
+    assert!((*size - 15.0).abs() < f32::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn component_ids_and_names_map_to_each_other() -> Result<()> {
+    let mut world = World::new();
+    let location_id = world.register("location")?;
+
+    assert_eq!(world.component_id("location")?, location_id);
+    assert_eq!(world.component_name(location_id), Some("location"));
+    assert!(world.component_id(ENTITY_ID).is_ok());
+    Ok(())
+}
+
+#[test]
+fn registering_a_component_twice_is_an_error() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+
+    match world.register("location") {
+        Err(BbEcsError::ComponentAlreadyRegistered { name }) => assert_eq!(name, "location"),
+        _ => panic!("expected a ComponentAlreadyRegistered error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn registering_after_spawning_entities() -> Result<()> {
+    let mut world = World::new();
+    world.spawn_entity()?;
+
+    let size_id = world.register("size")?;
+    world.spawn_entity()?.with_component(size_id, 15.0_f32)?;
+
+    let query = world.query(vec![size_id])?;
+    assert_eq!(query.get(size_id).unwrap().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn bitmap_errors_name_the_component() -> Result<()> {
+    let mut registry = ComponentRegistry::new();
+    let location_id = registry.register("location".to_owned())?;
+    let mut bitmap = BitMap::new();
+    bitmap.spawn_entity(0);
+
+    match bitmap.insert(location_id, &registry) {
+        Err(BbEcsError::BitMapInsertBeforeRegister { name }) => assert_eq!(name, "location"),
+        _ => panic!("expected a BitMapInsertBeforeRegister error"),
+    }
+    match bitmap.query(&[location_id], &registry) {
+        Err(BbEcsError::BitMapComponentNotFound { name }) => assert_eq!(name, "location"),
+        _ => panic!("expected a BitMapComponentNotFound error"),
+    }
+    Ok(())
+}
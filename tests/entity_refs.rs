@@ -0,0 +1,206 @@
+use std::rc::Rc;
+
+use bbecs::data_types::entity_ref::{EntityRef, OnDespawn};
+use bbecs::data_types::point::Point;
+use bbecs::errors::BbEcsError;
+use bbecs::resources::resource::ResourceCast;
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+#[test]
+fn references_point_at_living_entities() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("target")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("target", EntityRef::new(0, OnDespawn::Clear))?;
+    world.update()?;
+
+    let target = world.get::<EntityRef>(1, "target")?;
+    assert_eq!(target.get(), Some(0));
+    assert_eq!(
+        *world.get::<Point>(target.get().unwrap(), "location")?,
+        Point::new(10.0, 10.0)
+    );
+    Ok(())
+}
+
+#[test]
+fn deleting_the_target_clears_the_reference() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("target")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("target", EntityRef::new(0, OnDespawn::Clear))?;
+
+    world.delete_by_id(0)?;
+    world.update()?;
+
+    let target = world.get::<EntityRef>(1, "target")?;
+    assert_eq!(target.get(), None);
+    assert!(!target.is_dangling());
+    Ok(())
+}
+
+#[test]
+fn deleting_the_target_flags_the_reference() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("target")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("target", EntityRef::new(0, OnDespawn::Flag))?;
+
+    world.delete_by_id(0)?;
+    world.update()?;
+
+    let target = world.get::<EntityRef>(1, "target")?;
+    assert_eq!(target.get(), None);
+    assert!(target.is_dangling());
+    assert_eq!(target.dangling_target(), Some(0));
+    Ok(())
+}
+
+#[test]
+fn deleting_other_entities_keeps_the_reference() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("target")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("target", EntityRef::new(0, OnDespawn::Clear))?;
+    world.spawn_entity()?;
+
+    world.delete_by_id(2)?;
+    world.update()?;
+
+    assert_eq!(world.get::<EntityRef>(1, "target")?.get(), Some(0));
+    Ok(())
+}
+
+#[test]
+fn deleting_the_target_clears_resource_references() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("target")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("target", EntityRef::new(0, OnDespawn::Clear))?;
+    world.add_resource(
+        "camera target".to_owned(),
+        EntityRef::new(0, OnDespawn::Clear),
+    );
+
+    world.delete_by_id(0)?;
+    world.update()?;
+
+    let wrapped_resource = world.get_resource("camera target")?.borrow();
+    let camera_target: &EntityRef = wrapped_resource.cast()?;
+    assert_eq!(camera_target.get(), None);
+    Ok(())
+}
+
+#[test]
+fn borrowed_unrelated_resources_dont_stop_the_update() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("target")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("target", EntityRef::new(0, OnDespawn::Clear))?;
+    world.add_resource("size".to_owned(), 15.0_f32);
+    let size = Rc::clone(world.get_resource("size")?);
+    let _size = size.borrow();
+
+    world.delete_by_id(0)?;
+    world.update()?;
+
+    assert_eq!(world.get::<EntityRef>(1, "target")?.get(), None);
+    Ok(())
+}
+
+#[test]
+fn borrowed_resource_references_stop_the_update_before_deleting() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("target")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("target", EntityRef::new(0, OnDespawn::Clear))?;
+    world.add_resource(
+        "camera target".to_owned(),
+        EntityRef::new(0, OnDespawn::Clear),
+    );
+    let camera_target = Rc::clone(world.get_resource("camera target")?);
+    let _camera_target = camera_target.borrow_mut();
+
+    world.delete_by_id(0)?;
+    assert!(matches!(
+        world.update(),
+        Err(BbEcsError::BorrowConflict { name }) if name == "camera target"
+    ));
+    assert_eq!(*world.get::<Point>(0, "location")?, Point::new(10.0, 10.0));
+    assert_eq!(world.get::<EntityRef>(1, "target")?.get(), Some(0));
+    Ok(())
+}
+
+#[test]
+fn mutably_borrowed_unrelated_resources_dont_stop_the_update() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("target")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("target", EntityRef::new(0, OnDespawn::Clear))?;
+    world.add_resource("score".to_owned(), 0_u32);
+    let score = Rc::clone(world.get_resource("score")?);
+    let _score = score.borrow_mut();
+
+    world.delete_by_id(0)?;
+    world.update()?;
+
+    assert_eq!(world.get::<EntityRef>(1, "target")?.get(), None);
+    Ok(())
+}
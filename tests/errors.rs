@@ -0,0 +1,142 @@
+use bbecs::components::CastComponents;
+use bbecs::data_types::point::Point;
+use bbecs::errors::BbEcsError;
+use bbecs::resources::resource::ResourceCast;
+use bbecs::world::{DataWrapper, World, WorldMethods};
+use eyre::Result;
+
+#[test]
+fn casting_a_component_to_the_wrong_type() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+
+    let query = world.query(vec!["location"])?;
+    let result: bbecs::errors::Result<&DataWrapper<f32>> = query.get("location").unwrap()[0].cast();
+
+    assert!(matches!(
+        result,
+        Err(BbEcsError::CastingComponents {
+            name: None,
+            expected: "f32",
+            found: "Point"
+        })
+    ));
+    Ok(())
+}
+
+#[test]
+fn casting_a_resource_to_the_wrong_type() -> Result<()> {
+    let mut world = World::new();
+    world.add_resource("size".to_owned(), 15.0_f32);
+
+    let wrapped_resource = world.get_resource("size")?.borrow();
+    let result: bbecs::errors::Result<&u32> = wrapped_resource.cast();
+
+    assert!(matches!(
+        result,
+        Err(BbEcsError::CastingResource {
+            name: None,
+            expected: "u32",
+            found: "f32"
+        })
+    ));
+    Ok(())
+}
+
+#[test]
+fn casting_errors_name_the_component_when_borrowed_by_name() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+
+    let query = world.query(vec!["location"])?;
+    let result: bbecs::errors::Result<std::cell::Ref<f32>> =
+        query.get("location").unwrap()[0].try_borrow("location");
+
+    match result {
+        Err(error @ BbEcsError::CastingComponents { .. }) => {
+            assert!(
+                matches!(&error, BbEcsError::CastingComponents { name: Some(name), .. } if name == "location")
+            );
+            assert_eq!(
+                error.to_string(),
+                "attempted to cast component `location` of type `Point` to `f32`"
+            );
+        }
+        _ => panic!("expected a CastingComponents error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn casting_errors_name_the_resource_from_the_resource_getters() -> Result<()> {
+    let mut world = World::new();
+    world.add_resource("size".to_owned(), 15.0_f32);
+
+    let result = (|| -> bbecs::errors::Result<()> {
+        let size: &u32;
+        bbecs::get_resource!(size, world, "size");
+        assert_eq!(*size, 15);
+        Ok(())
+    })();
+
+    match result {
+        Err(error @ BbEcsError::CastingResource { .. }) => {
+            assert_eq!(
+                error.to_string(),
+                "attempted to cast resource `size` of type `f32` to `u32`"
+            );
+        }
+        _ => panic!("expected a CastingResource error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn inserting_an_unregistered_component() -> Result<()> {
+    let mut world = World::new();
+    let result = world.spawn_entity()?.with_component("size", 15.0_f32);
+
+    match result {
+        Err(BbEcsError::NeedToRegister { name }) => assert_eq!(name, "size"),
+        _ => panic!("expected a NeedToRegister error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn querying_an_unregistered_component() {
+    let world = World::new();
+
+    match world.query(vec!["size"]) {
+        Err(BbEcsError::BitMapComponentNotFound { name }) => assert_eq!(name, "size"),
+        _ => panic!("expected a BitMapComponentNotFound error"),
+    }
+}
+
+#[test]
+fn getting_a_missing_resource() {
+    let world = World::new();
+
+    match world.get_resource("size") {
+        Err(BbEcsError::ResourceNotFound { name }) => assert_eq!(name, "size"),
+        _ => panic!("expected a ResourceNotFound error"),
+    }
+}
+
+#[test]
+fn deleting_an_entity_that_does_not_exist() -> Result<()> {
+    let mut world = World::new();
+    world.spawn_entity()?;
+
+    assert!(matches!(
+        world.delete_by_id(5),
+        Err(BbEcsError::NoSuchEntity { entity_id: 5 })
+    ));
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use bbecs::data_types::point::Point;
+use bbecs::errors::BbEcsError;
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+#[test]
+fn getting_a_component_of_an_entity() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("health")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_component("health", 100_u32)?;
+    world.spawn_entity()?.with_component("health", 50_u32)?;
+
+    assert_eq!(*world.get::<u32>(1, "health")?, 100);
+    assert_eq!(*world.get::<u32>(2, "health")?, 50);
+    assert_eq!(*world.get::<Point>(1, "location")?, Point::new(10.0, 10.0));
+    Ok(())
+}
+
+#[test]
+fn getting_a_component_of_an_entity_with_its_id() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("health")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_component("health", 100_u32)?;
+    world.spawn_entity()?.with_component("health", 50_u32)?;
+    let health_id = world.component_id("health")?;
+
+    assert_eq!(*world.get::<u32>(2, health_id)?, 50);
+    Ok(())
+}
+
+#[test]
+fn mutating_a_component_of_an_entity() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("health")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_component("health", 100_u32)?;
+    world.spawn_entity()?.with_component("health", 50_u32)?;
+
+    *world.get_mut::<u32>(2, "health")? -= 25;
+
+    assert_eq!(*world.get::<u32>(2, "health")?, 25);
+    assert_eq!(*world.get::<u32>(1, "health")?, 100);
+    Ok(())
+}
+
+#[test]
+fn getting_a_component_the_entity_does_not_have() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("health")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_component("health", 100_u32)?;
+    world.spawn_entity()?.with_component("health", 50_u32)?;
+
+    match world.get::<u32>(0, "health") {
+        Err(BbEcsError::NoSuchComponent { name, entity_id }) => {
+            assert_eq!(name, "health");
+            assert_eq!(entity_id, 0);
+        }
+        _ => panic!("expected a NoSuchComponent error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn getting_a_component_of_a_deleted_entity() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("health")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_component("health", 100_u32)?;
+    world.spawn_entity()?.with_component("health", 50_u32)?;
+    world.delete_by_id(1)?;
+    world.update()?;
+
+    assert!(matches!(
+        world.get::<u32>(1, "health"),
+        Err(BbEcsError::NoSuchEntity { entity_id: 1 })
+    ));
+    assert_eq!(*world.get::<u32>(2, "health")?, 50);
+    Ok(())
+}
+
+#[test]
+fn getting_a_component_as_the_wrong_type() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("health")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_component("health", 100_u32)?;
+    world.spawn_entity()?.with_component("health", 50_u32)?;
+
+    assert!(matches!(
+        world.get::<f32>(1, "health"),
+        Err(BbEcsError::CastingComponents { .. })
+    ));
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use bbecs::data_types::circle::Circle;
+use bbecs::data_types::point::Point;
+use bbecs::data_types::rect::Rect;
+
+#[test]
+fn separating_overlapping_circles_with_the_contact() {
+    let mut asteroid = Circle::new(Point::new(0.0, 0.0), 10.0);
+    let bullet = Circle::new(Point::new(6.0, 8.0), 5.0);
+
+    let contact = asteroid.contact_circle(&bullet).unwrap();
+    assert_eq!(contact.normal, Point::new(0.6, 0.8));
+    assert!((contact.penetration - 5.0).abs() < 0.0001);
+
+    asteroid.center -= contact.normal * contact.penetration;
+    assert!(!asteroid.overlaps_circle(&bullet));
+}
+
+#[test]
+fn circles_that_do_not_overlap_have_no_contact() {
+    let asteroid = Circle::new(Point::new(0.0, 0.0), 10.0);
+    let bullet = Circle::new(Point::new(20.0, 0.0), 5.0);
+
+    assert!(asteroid.contact_circle(&bullet).is_none());
+    assert_eq!(asteroid.penetration_depth(&bullet), 0.0);
+}
+
+#[test]
+fn circle_against_rect_corner() {
+    let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    let circle = Circle::new(Point::new(13.0, 14.0), 6.0);
+
+    let contact = circle.contact_rect(&rect).unwrap();
+
+    assert_eq!(contact.normal, Point::new(-0.6, -0.8));
+    assert!((contact.penetration - 1.0).abs() < 0.0001);
+}
+
+#[test]
+fn circle_with_its_center_inside_a_rect_is_pushed_out_the_closest_edge() {
+    let rect = Rect::new(Point::new(0.0, 0.0), Point::new(100.0, 10.0));
+    let circle = Circle::new(Point::new(50.0, 8.0), 3.0);
+
+    let contact = circle.contact_rect(&rect).unwrap();
+
+    assert_eq!(contact.normal, Point::new(0.0, -1.0));
+    assert!((contact.penetration - 5.0).abs() < 0.0001);
+    assert!(circle.overlaps_rect(&rect));
+}
+
+#[test]
+fn circle_outside_of_a_rect_has_no_contact() {
+    let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    let circle = Circle::new(Point::new(20.0, 5.0), 5.0);
+
+    assert!(circle.contact_rect(&rect).is_none());
+    assert!(!circle.overlaps_rect(&rect));
+}
+
+#[test]
+fn circle_centered_on_the_right_edge_of_a_rect() {
+    let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    let circle = Circle::new(Point::new(10.0, 5.0), 3.0);
+
+    let contact = circle.contact_rect(&rect).unwrap();
+
+    assert_eq!(contact.normal, Point::new(-1.0, 0.0));
+    assert_eq!(contact.penetration, 3.0);
+}
+
+#[test]
+fn circle_centered_on_the_bottom_edge_of_a_rect() {
+    let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    let circle = Circle::new(Point::new(5.0, 10.0), 3.0);
+
+    let contact = circle.contact_rect(&rect).unwrap();
+
+    assert_eq!(contact.normal, Point::new(0.0, -1.0));
+    assert_eq!(contact.penetration, 3.0);
+}
+
+#[test]
+fn circle_without_a_radius_on_the_edge_of_a_rect_does_not_touch_it() {
+    let rect = Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    let circle = Circle::new(Point::new(10.0, 5.0), 0.0);
+
+    assert!(!circle.overlaps_rect(&rect));
+    assert!(circle.contact_rect(&rect).is_none());
+}
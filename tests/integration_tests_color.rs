@@ -1,3 +1,5 @@
+#![cfg(feature = "ggez")]
+
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -92,3 +94,12 @@ fn integration_test_point_query_mut() -> Result<()> {
     assert_eq!(*color, Color::new(0.6, 0.2, 0.8, 1.0));
     Ok(())
 }
+
+#[test]
+fn converting_between_bbecs_and_ggez_colors() {
+    let color = bbecs::data_types::color::Color::new(0.5, 0.2, 0.8, 1.0);
+    let ggez_color: Color = color.into();
+
+    assert_eq!(ggez_color, RESOURCE);
+    assert_eq!(bbecs::data_types::color::Color::from(ggez_color), color);
+}
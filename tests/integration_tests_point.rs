@@ -98,3 +98,27 @@ fn integration_test_point_query_mut() -> Result<()> {
     assert_eq!(*location, Point::new(10.0, 0.0));
     Ok(())
 }
+
+#[test]
+fn using_points_as_vectors() {
+    let mut location = Point::new(0.0, 0.0);
+    let velocity = Point::from([1.0, 2.0]);
+    let acceleration = Point::from((0.5, -0.5));
+
+    location += velocity * 2.0 + acceleration;
+    location -= -velocity / 2.0;
+
+    assert_eq!(location, Point::new(3.0, 4.5));
+}
+
+#[test]
+#[cfg(feature = "ggez")]
+fn passing_points_to_ggez_draw_params() {
+    let location = Point::new(10.0, 20.0);
+    let draw_param = ggez::graphics::DrawParam::new()
+        .dest(location)
+        .scale(Point::new(2.0, 2.0));
+
+    assert_eq!(draw_param.dest, location.into());
+    assert_eq!(draw_param.scale, Point::new(2.0, 2.0).into());
+}
@@ -0,0 +1,70 @@
+use bbecs::data_types::point::Point;
+use bbecs::data_types::rect::Rect;
+use bbecs::resources::resource::ResourceCast;
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+#[test]
+fn checking_hitboxes_stored_as_components() -> Result<()> {
+    let mut world = World::new();
+    world.register("hitbox")?;
+    world.spawn_entity()?.with_component(
+        "hitbox",
+        Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0)),
+    )?;
+    world.spawn_entity()?.with_component(
+        "hitbox",
+        Rect::from_center(Point::new(12.0, 12.0), Point::new(6.0, 6.0)),
+    )?;
+
+    let first = world.get::<Rect>(0, "hitbox")?;
+    let second = world.get::<Rect>(1, "hitbox")?;
+
+    assert!(first.intersects(&second));
+    assert_eq!(
+        first.intersection(&second),
+        Some(Rect::new(Point::new(9.0, 9.0), Point::new(1.0, 1.0)))
+    );
+    Ok(())
+}
+
+#[test]
+fn keeping_locations_inside_the_arena_resource() -> Result<()> {
+    let mut world = World::new();
+    world.add_resource(
+        "arena".to_owned(),
+        Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0)),
+    );
+
+    let wrapped_arena = world.get_resource("arena")?.borrow();
+    let arena: &Rect = wrapped_arena.cast()?;
+
+    assert!(arena.contains_point(&Point::new(400.0, 300.0)));
+    assert!(!arena.contains_point(&Point::new(900.0, 300.0)));
+    assert_eq!(arena.center(), Point::new(400.0, 300.0));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ggez")]
+fn converting_between_bbecs_and_ggez_rects() {
+    let rect = Rect::new(Point::new(1.0, 2.0), Point::new(3.0, 4.0));
+    let ggez_rect: ggez::graphics::Rect = rect.into();
+
+    assert_eq!(ggez_rect, ggez::graphics::Rect::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Rect::from(ggez_rect), rect);
+}
+
+#[test]
+fn closest_point_of_a_rect_with_a_negative_size() {
+    let rect = Rect::new(Point::new(10.0, 10.0), Point::new(-10.0, -10.0));
+
+    assert_eq!(
+        rect.closest_point(&Point::new(15.0, -5.0)),
+        Point::new(10.0, 0.0)
+    );
+    assert_eq!(
+        rect.closest_point(&Point::new(5.0, 5.0)),
+        Point::new(5.0, 5.0)
+    );
+}
@@ -0,0 +1,71 @@
+use std::cell::{Ref, RefMut};
+use std::f32::consts::FRAC_PI_2;
+
+use bbecs::components::CastComponents;
+use bbecs::data_types::point::Point;
+use bbecs::data_types::transform::Transform;
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+#[test]
+fn composing_parent_and_child_transforms() {
+    let ship = Transform::new(Point::new(100.0, 100.0), FRAC_PI_2, Point::new(2.0, 2.0));
+    let turret = Transform::new(Point::new(10.0, 0.0), FRAC_PI_2, Point::new(0.5, 0.5));
+
+    let world_turret = ship * turret;
+
+    assert!(world_turret.position.distance_to(&Point::new(100.0, 120.0)) < 0.0001);
+    assert!((world_turret.rotation - FRAC_PI_2 * 2.0).abs() < 0.0001);
+    assert_eq!(world_turret.scale, Point::new(1.0, 1.0));
+
+    let barrel_tip = Point::new(4.0, 0.0);
+    let composed = world_turret.transform_point(&barrel_tip);
+    let nested = ship.transform_point(&turret.transform_point(&barrel_tip));
+    assert!(composed.distance_to(&nested) < 0.0001);
+}
+
+#[test]
+fn composing_with_the_inverse_is_the_identity() {
+    let ship = Transform::new(Point::new(-20.0, 35.0), 0.7, Point::new(3.0, 3.0));
+
+    let identity = ship * ship.inverse();
+    let point = Point::new(12.0, -4.0);
+
+    assert!(identity.transform_point(&point).distance_to(&point) < 0.0001);
+}
+
+#[test]
+fn default_transform_is_the_identity() {
+    assert_eq!(Transform::default(), Transform::identity());
+}
+
+#[test]
+fn storing_transforms_as_components() -> Result<()> {
+    let mut world = World::new();
+    world.register("transform")?;
+    world
+        .spawn_entity()?
+        .with_component("transform", Transform::from_position(Point::new(5.0, 5.0)))?;
+
+    let query = world.query(vec!["transform"])?;
+    let mut transform: RefMut<Transform> =
+        query.get("transform").unwrap()[0].try_borrow_mut("transform")?;
+    transform.translate(Point::new(1.0, 0.0));
+    drop(transform);
+
+    let transform: Ref<Transform> = query.get("transform").unwrap()[0].try_borrow("transform")?;
+    assert_eq!(transform.position, Point::new(6.0, 5.0));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ggez")]
+fn converting_transforms_to_draw_params() {
+    let transform = Transform::new(Point::new(10.0, 20.0), 1.5, Point::new(2.0, 3.0));
+
+    let draw_param: ggez::graphics::DrawParam = transform.into();
+
+    assert_eq!(draw_param.dest, Point::new(10.0, 20.0).into());
+    assert_eq!(draw_param.rotation, 1.5);
+    assert_eq!(draw_param.scale, Point::new(2.0, 3.0).into());
+}
@@ -0,0 +1,99 @@
+use bbecs::data_types::point::Point;
+use bbecs::errors::BbEcsError;
+use bbecs::world::{World, WorldMethods, ENTITY_ID, TO_BE_DELETED};
+use eyre::Result;
+
+#[test]
+fn counting_entities() -> Result<()> {
+    let mut world = World::new();
+    assert!(world.is_empty());
+    assert_eq!(world.len(), 0);
+
+    world.spawn_entity()?;
+    world.spawn_entity()?;
+    assert!(!world.is_empty());
+    assert_eq!(world.len(), 2);
+
+    world.delete_by_id(0)?;
+    assert_eq!(world.len(), 2);
+    world.update()?;
+    assert_eq!(world.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn listing_registered_components() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("size")?;
+    world.unregister("size")?;
+
+    let components = world.registered_components();
+
+    assert!(components.contains(&"location"));
+    assert!(!components.contains(&ENTITY_ID));
+    assert!(!components.contains(&TO_BE_DELETED));
+    assert!(!components.contains(&"size"));
+    Ok(())
+}
+
+#[test]
+fn listing_the_components_of_an_entity() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("size")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+    world.spawn_entity()?.with_component("size", 15.0_f32)?;
+
+    let components = world.components_of(1)?;
+
+    assert!(components.contains(&"size"));
+    assert!(!components.contains(&ENTITY_ID));
+    assert!(!components.contains(&TO_BE_DELETED));
+    assert!(!components.contains(&"location"));
+    assert!(matches!(
+        world.components_of(2),
+        Err(BbEcsError::NoSuchEntity { entity_id: 2 })
+    ));
+    Ok(())
+}
+
+#[test]
+fn checking_if_an_entity_exists() -> Result<()> {
+    let mut world = World::new();
+    world.spawn_entity()?;
+    world.spawn_entity()?;
+
+    world.delete_by_id(0)?;
+    world.update()?;
+
+    assert!(!world.contains(0));
+    assert!(world.contains(1));
+    Ok(())
+}
+
+#[test]
+fn finding_entities_after_deleting_others() -> Result<()> {
+    let mut world = World::new();
+    world.register("size")?;
+    for size in 0..5 {
+        world.spawn_entity()?.with_component("size", size as f32)?;
+    }
+
+    world.delete_by_id(1)?;
+    world.delete_by_id(3)?;
+    world.update()?;
+    world.spawn_entity()?.with_component("size", 5.0_f32)?;
+
+    assert!(!world.contains(3));
+    assert_eq!(*world.get::<f32>(2, "size")?, 2.0);
+    assert_eq!(*world.get::<f32>(4, "size")?, 4.0);
+    assert_eq!(*world.get::<f32>(5, "size")?, 5.0);
+    assert!(matches!(
+        world.get::<f32>(3, "size"),
+        Err(BbEcsError::NoSuchEntity { entity_id: 3 })
+    ));
+    Ok(())
+}
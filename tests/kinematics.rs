@@ -0,0 +1,93 @@
+use bbecs::data_types::point::Point;
+use bbecs::kinematics::{Integrator, KinematicsSystem};
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+fn location_after_one_run(integrator: Integrator) -> Result<Point> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("acceleration")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("velocity", Point::new(2.0, 0.0))?
+        .with_component("acceleration", Point::new(0.0, 4.0))?;
+    world.add_resource("delta time".to_owned(), 0.5_f32);
+    let mut system = KinematicsSystem::new();
+    system.integrator = integrator;
+    system.delta_time = Some("delta time".to_owned());
+
+    system.run(&world)?;
+
+    assert_eq!(*world.get::<Point>(0, "velocity")?, Point::new(2.0, 2.0));
+    let location = *world.get::<Point>(0, "location")?;
+    Ok(location)
+}
+
+#[test]
+fn integrators_scaled_by_delta_time() -> Result<()> {
+    assert_eq!(
+        location_after_one_run(Integrator::ExplicitEuler)?,
+        Point::new(1.0, 0.0)
+    );
+    assert_eq!(
+        location_after_one_run(Integrator::SemiImplicitEuler)?,
+        Point::new(1.0, 1.0)
+    );
+    assert_eq!(
+        location_after_one_run(Integrator::Verlet)?,
+        Point::new(1.0, 0.5)
+    );
+    Ok(())
+}
+
+#[test]
+fn drag_and_max_speed_limit_the_velocity() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("velocity")?;
+    world.register("acceleration")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("velocity", Point::new(10.0, 0.0))?
+        .with_component("acceleration", Point::new(0.0, 0.0))?;
+    world.add_resource("delta time".to_owned(), 0.5_f32);
+    let mut system = KinematicsSystem::new();
+    system.drag = 0.5;
+    system.delta_time = Some("delta time".to_owned());
+
+    system.run(&world)?;
+    assert_eq!(*world.get::<Point>(0, "velocity")?, Point::new(7.5, 0.0));
+
+    system.max_speed = Some(3.0);
+    system.run(&world)?;
+    let velocity = *world.get::<Point>(0, "velocity")?;
+    assert!(velocity.distance_to(&Point::new(3.0, 0.0)) < 0.0001);
+    Ok(())
+}
+
+#[test]
+fn custom_component_names() -> Result<()> {
+    let mut world = World::new();
+    world.register("position")?;
+    world.register("speed")?;
+    world.register("force")?;
+    world
+        .spawn_entity()?
+        .with_component("position", Point::new(5.0, 5.0))?
+        .with_component("speed", Point::new(0.0, -1.0))?
+        .with_component("force", Point::new(0.0, 0.0))?;
+    let mut system = KinematicsSystem::new();
+    system.location = "position".to_owned();
+    system.velocity = "speed".to_owned();
+    system.acceleration = "force".to_owned();
+
+    for _ in 0..3 {
+        system.run(&world)?;
+    }
+
+    assert_eq!(*world.get::<Point>(0, "position")?, Point::new(5.0, 2.0));
+    Ok(())
+}
@@ -5,7 +5,6 @@ use bbecs::components::CastComponents;
 use bbecs::data_types::point::Point;
 use bbecs::world::{World, WorldMethods};
 use eyre::Result;
-use ggez::graphics::Text;
 
 #[test]
 fn querying_for_multiple_components() -> Result<()> {
@@ -82,7 +81,10 @@ fn query_for_entities_when_not_all_entities_have_same_number_of_components() ->
 }
 
 #[test]
+#[cfg(feature = "ggez")]
 fn querying_for_messages() -> Result<()> {
+    use ggez::graphics::Text;
+
     let mut world = World::new();
 
     world.register("location")?;
@@ -107,3 +109,51 @@ fn querying_for_messages() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn querying_for_list_components() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("trail")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(1.0, 1.0))?
+        .with_component("trail", vec![Point::new(0.0, 0.0)])?;
+
+    {
+        let query = world.query(vec!["location", "trail"])?;
+        let locations = query.get("location").unwrap();
+        let trails = query.get("trail").unwrap();
+        let wrapped_location: &Rc<RefCell<Point>> = locations[0].cast()?;
+        let wrapped_trail: &Rc<RefCell<Vec<Point>>> = trails[0].cast()?;
+        wrapped_trail.borrow_mut().push(*wrapped_location.borrow());
+    }
+
+    let trail = world.get::<Vec<Point>>(0, "trail")?;
+    assert_eq!(*trail, vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)]);
+    Ok(())
+}
+
+#[test]
+fn casting_list_components_to_the_wrong_list_type() -> Result<()> {
+    let mut world = World::new();
+    world.register("trail")?;
+    world
+        .spawn_entity()?
+        .with_component("trail", vec![Point::new(0.0, 0.0)])?;
+
+    match world.get::<Vec<f32>>(0, "trail") {
+        Err(bbecs::errors::BbEcsError::CastingComponents {
+            name,
+            expected,
+            found,
+        }) => {
+            assert_eq!(name.as_deref(), Some("trail"));
+            assert_eq!(expected, "Vec<f32>");
+            assert_eq!(found, "Vec<Point>");
+        }
+        _ => panic!("expected a CastingComponents error"),
+    }
+    Ok(())
+}
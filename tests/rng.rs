@@ -0,0 +1,84 @@
+use bbecs::data_types::circle::Circle;
+use bbecs::data_types::point::Point;
+use bbecs::data_types::rect::Rect;
+use bbecs::data_types::rng::Rng;
+use bbecs::world::World;
+use eyre::Result;
+
+fn spawn_asteroids(seed: u64) -> Result<Vec<Point>> {
+    let mut world = World::new();
+    world.seed_rng(seed);
+    let arena = Rect::new(Point::new(0.0, 0.0), Point::new(800.0, 600.0));
+
+    Ok((0..10)
+        .map(|_| Point::random_in_rect(world.rng_mut(), &arena))
+        .collect())
+}
+
+#[test]
+fn the_same_seed_gives_the_same_session() -> Result<()> {
+    assert_eq!(spawn_asteroids(1234)?, spawn_asteroids(1234)?);
+    assert_ne!(spawn_asteroids(1234)?, spawn_asteroids(4321)?);
+    Ok(())
+}
+
+#[test]
+fn clearing_the_world_keeps_the_rng() -> Result<()> {
+    let mut world = World::new();
+    world.seed_rng(7);
+    world.rng_mut().next_u64();
+
+    world.clear_entities(true)?;
+    world.clear_resources();
+
+    assert_eq!(world.rng().seed(), 7);
+    assert_ne!(*world.rng(), Rng::new(7));
+    Ok(())
+}
+
+#[test]
+fn reseeding_starts_the_sequence_over() {
+    let mut rng = Rng::new(99);
+    let first: Vec<u32> = (0..5).map(|_| rng.next_u32()).collect();
+
+    rng.reseed(99);
+    let second: Vec<u32> = (0..5).map(|_| rng.next_u32()).collect();
+
+    assert_eq!(first, second);
+    assert_eq!(rng.seed(), 99);
+}
+
+#[test]
+fn ranges_stay_inside_their_bounds() {
+    let mut rng = Rng::new(5);
+    let mut lowest = f32::MAX;
+    let mut highest = f32::MIN;
+
+    for _ in 0..10_000 {
+        let value = rng.range(-1.0, 1.0);
+        lowest = lowest.min(value);
+        highest = highest.max(value);
+        assert!((3..6).contains(&rng.range_u32(3, 6)));
+    }
+
+    assert!((-1.0..-0.99).contains(&lowest));
+    assert!((0.99..1.0).contains(&highest));
+    assert_eq!(rng.range_u32(10, 10), 10);
+}
+
+#[test]
+fn random_points_in_circles_are_spread_over_the_area() {
+    let mut rng = Rng::new(11);
+    let circle = Circle::new(Point::new(-50.0, 25.0), 10.0);
+    let samples = 10_000;
+
+    let inside_half_radius = (0..samples)
+        .map(|_| Point::random_in_circle(&mut rng, &circle))
+        .inspect(|point| assert!(circle.contains_point(point)))
+        .filter(|point| point.distance_to(&circle.center) < circle.radius / 2.0)
+        .count();
+
+    // a quarter of the area of the circle is within half of its radius
+    let ratio = inside_half_radius as f32 / samples as f32;
+    assert!((ratio - 0.25).abs() < 0.02);
+}
@@ -0,0 +1,225 @@
+use bbecs::data_types::collider::Collider;
+use bbecs::data_types::collision_layers::CollisionLayers;
+use bbecs::data_types::point::Point;
+use bbecs::data_types::rect::Rect;
+use bbecs::data_types::spatial_hash::SpatialHash;
+use bbecs::errors::BbEcsError;
+use bbecs::resources::resource::ResourceCast;
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+fn sorted(mut entity_ids: Vec<u32>) -> Vec<u32> {
+    entity_ids.sort_unstable();
+    entity_ids
+}
+
+#[test]
+fn queries_find_entities_across_cell_borders() {
+    let mut spatial_hash = SpatialHash::new(10.0);
+    spatial_hash.insert(0, Point::new(9.0, 9.0));
+    spatial_hash.insert(1, Point::new(11.0, 11.0));
+    spatial_hash.insert(2, Point::new(-1.0, 9.0));
+    spatial_hash.insert(3, Point::new(30.0, 30.0));
+
+    let nearby = spatial_hash.query_radius(&Point::new(10.0, 10.0), 3.0);
+    let in_rect =
+        spatial_hash.query_rect(&Rect::new(Point::new(-5.0, 0.0), Point::new(20.0, 20.0)));
+
+    assert_eq!(sorted(nearby), vec![0, 1]);
+    assert_eq!(sorted(in_rect), vec![0, 1, 2]);
+}
+
+#[test]
+fn moving_and_removing_entities() {
+    let mut spatial_hash = SpatialHash::new(10.0);
+    spatial_hash.insert(0, Point::new(5.0, 5.0));
+    spatial_hash.insert(0, Point::new(55.0, 5.0));
+
+    assert_eq!(spatial_hash.len(), 1);
+    assert!(spatial_hash
+        .query_radius(&Point::new(5.0, 5.0), 1.0)
+        .is_empty());
+    assert_eq!(
+        spatial_hash.query_radius(&Point::new(55.0, 5.0), 1.0),
+        vec![0]
+    );
+
+    assert_eq!(spatial_hash.remove(0), Some(Point::new(55.0, 5.0)));
+    assert_eq!(spatial_hash.remove(0), None);
+    assert!(spatial_hash.is_empty());
+}
+
+#[test]
+fn deleted_entities_are_removed_from_spatial_hash_resources() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(1.0, 0.0))?;
+    world.add_resource("spatial hash".to_owned(), SpatialHash::new(10.0));
+    world.update_spatial_hash("spatial hash", "location")?;
+
+    world.delete_by_id(0)?;
+    world.update()?;
+
+    let resource = world.borrow_resource("spatial hash")?;
+    let spatial_hash: &SpatialHash = resource.cast()?;
+    assert_eq!(
+        spatial_hash.query_radius(&Point::new(0.0, 0.0), 5.0),
+        vec![1]
+    );
+    Ok(())
+}
+
+#[test]
+fn clearing_entities_empties_spatial_hash_resources() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?;
+    world.add_resource("spatial hash".to_owned(), SpatialHash::new(10.0));
+    world.update_spatial_hash("spatial hash", "location")?;
+
+    world.clear_entities(true)?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(100.0, 100.0))?;
+
+    let resource = world.borrow_resource("spatial hash")?;
+    let spatial_hash: &SpatialHash = resource.cast()?;
+    assert!(spatial_hash.is_empty());
+    assert!(spatial_hash
+        .query_radius(&Point::new(0.0, 0.0), 5.0)
+        .is_empty());
+    Ok(())
+}
+
+#[test]
+fn updating_a_spatial_hash_that_does_not_exist() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+
+    assert!(matches!(
+        world.update_spatial_hash("spatial hash", "location"),
+        Err(BbEcsError::ResourceNotFound { .. })
+    ));
+    assert!(matches!(
+        world.update_spatial_hash("spatial hash", "position"),
+        Err(BbEcsError::ComponentNotFound { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn masked_queries_only_find_entities_in_the_mask() -> Result<()> {
+    const BULLET: CollisionLayers = CollisionLayers::layer(1);
+    const ENEMY: CollisionLayers = CollisionLayers::layer(2);
+
+    let mut world = World::new();
+    world.register("location")?;
+    world.register("collider")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_component("collider", Collider::circle(1.0).with_layers(BULLET))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(1.0, 0.0))?
+        .with_component("collider", Collider::circle(5.0).with_layers(ENEMY))?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(2.0, 0.0))?;
+    world.add_resource("spatial hash".to_owned(), SpatialHash::new(10.0));
+
+    world.update_spatial_hash_with_colliders("spatial hash", "location", "collider")?;
+
+    let resource = world.borrow_resource("spatial hash")?;
+    let spatial_hash: &SpatialHash = resource.cast()?;
+    let area = Rect::new(Point::new(-5.0, -5.0), Point::new(10.0, 10.0));
+    assert_eq!(spatial_hash.len(), 2);
+    assert_eq!(spatial_hash.layers(1), Some(ENEMY));
+    assert_eq!(spatial_hash.query_rect_masked(&area, ENEMY), vec![1]);
+    assert_eq!(
+        sorted(spatial_hash.query_rect_masked(&area, BULLET | ENEMY)),
+        vec![0, 1]
+    );
+    assert!(spatial_hash
+        .query_radius_masked(&Point::new(0.0, 0.0), 5.0, CollisionLayers::NONE)
+        .is_empty());
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn spatial_hashes_need_a_positive_cell_size() {
+    SpatialHash::new(0.0);
+}
+
+#[test]
+#[should_panic]
+fn spatial_hashes_need_a_finite_cell_size() {
+    SpatialHash::new(f32::NAN);
+}
+
+#[test]
+fn entities_at_locations_that_are_not_finite_are_not_inserted() {
+    let mut spatial_hash = SpatialHash::new(10.0);
+    spatial_hash.insert(0, Point::new(5.0, 5.0));
+    spatial_hash.insert(0, Point::new(f32::NAN, 5.0));
+    spatial_hash.insert(1, Point::new(5.0, f32::INFINITY));
+
+    assert!(spatial_hash.is_empty());
+    assert!(spatial_hash
+        .query_radius(&Point::new(5.0, 5.0), 10.0)
+        .is_empty());
+}
+
+#[test]
+fn huge_queries_only_visit_the_occupied_cells() {
+    let mut spatial_hash = SpatialHash::new(1.0);
+    spatial_hash.insert(0, Point::new(0.0, 0.0));
+    spatial_hash.insert(1, Point::new(1.0e30, -1.0e30));
+
+    let everything = spatial_hash.query_radius(&Point::new(0.0, 0.0), 1.0e31);
+    let huge_rect = Rect::new(Point::new(-1.0e12, -1.0e12), Point::new(2.0e12, 2.0e12));
+
+    assert_eq!(sorted(everything), vec![0, 1]);
+    assert_eq!(spatial_hash.query_rect(&huge_rect), vec![0]);
+}
+
+#[test]
+fn spatial_hashes_are_not_components() -> Result<()> {
+    let mut world = World::new();
+    world.register("spatial hash")?;
+
+    let result = world
+        .spawn_entity()?
+        .with_component("spatial hash", SpatialHash::new(10.0));
+
+    assert!(matches!(
+        result,
+        Err(BbEcsError::NotAComponent { name }) if name == "spatial hash"
+    ));
+    Ok(())
+}
+
+#[test]
+fn huge_and_small_queries_return_entities_in_the_same_order() {
+    let mut spatial_hash = SpatialHash::new(1.0);
+    for (entity_id, x, y) in [(0, 3.5, 0.5), (1, 0.5, 2.5), (2, 2.5, 1.5), (3, 0.5, 0.5)] {
+        spatial_hash.insert(entity_id, Point::new(x, y));
+    }
+
+    let small = spatial_hash.query_rect(&Rect::new(Point::new(0.0, 0.0), Point::new(4.0, 3.0)));
+    let huge = spatial_hash.query_rect(&Rect::new(
+        Point::new(-1.0e9, -1.0e9),
+        Point::new(2.0e9, 2.0e9),
+    ));
+
+    assert_eq!(small, vec![3, 1, 2, 0]);
+    assert_eq!(huge, small);
+}
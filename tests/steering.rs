@@ -0,0 +1,62 @@
+use std::cell::Ref;
+
+use bbecs::components::CastComponents;
+use bbecs::data_types::point::Point;
+use bbecs::steering::{self, SteeringSystem};
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+#[test]
+fn arriving_slows_down_near_the_target() {
+    let location = Point::new(0.0, 0.0);
+    let velocity = Point::new(0.0, 0.0);
+
+    let far = steering::arrive(&location, &velocity, &Point::new(50.0, 0.0), 4.0, 10.0);
+    let near = steering::arrive(&location, &velocity, &Point::new(2.5, 0.0), 4.0, 10.0);
+    let on_target = steering::arrive(&location, &velocity, &location, 4.0, 10.0);
+
+    assert_eq!(far, Point::new(4.0, 0.0));
+    assert_eq!(near, Point::new(1.0, 0.0));
+    assert_eq!(on_target, Point::new(0.0, 0.0));
+}
+
+#[test]
+fn separation_without_close_neighbors_does_nothing() {
+    let location = Point::new(0.0, 0.0);
+    let neighbors = [location, Point::new(50.0, 50.0)];
+
+    let force = steering::separation(&location, &Point::new(1.0, 0.0), &neighbors, 10.0, 3.0);
+
+    assert_eq!(force, Point::new(0.0, 0.0));
+}
+
+#[test]
+fn steering_system_with_custom_component_names() -> Result<()> {
+    let mut world = World::new();
+    world.register("position")?;
+    world.register("velocity")?;
+    world.register("waypoint")?;
+    world.register("acceleration")?;
+    world
+        .spawn_entity()?
+        .with_component("position", Point::new(0.0, 0.0))?
+        .with_component("velocity", Point::new(0.0, 0.0))?
+        .with_component("waypoint", Point::new(0.0, 100.0))?
+        .with_component("acceleration", Point::new(1.0, 0.0))?;
+    world
+        .spawn_entity()?
+        .with_component("position", Point::new(0.0, 0.0))?
+        .with_component("velocity", Point::new(0.0, 0.0))?;
+
+    let mut system = SteeringSystem::new(3.0, 10.0);
+    system.location = "position".to_owned();
+    system.target = "waypoint".to_owned();
+    system.run(&world)?;
+
+    let query = world.query(vec!["acceleration"])?;
+    let accelerations = query.get("acceleration").unwrap();
+    let acceleration: Ref<Point> = accelerations[0].try_borrow("acceleration")?;
+    assert_eq!(accelerations.len(), 1);
+    assert_eq!(*acceleration, Point::new(1.0, 3.0));
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use std::cell::Ref;
+
+use bbecs::components::CastComponents;
+use bbecs::data_types::point::Point;
+use bbecs::errors::BbEcsError;
+use bbecs::world::{World, WorldMethods, ENTITY_ID};
+use eyre::Result;
+
+fn queried_ids(world: &World, tag: &str) -> Result<Vec<u32>> {
+    let query = world.query(vec![ENTITY_ID, tag])?;
+    let mut ids = vec![];
+    for id in query.get(ENTITY_ID).unwrap() {
+        let id: Ref<u32> = id.try_borrow(ENTITY_ID)?;
+        ids.push(*id);
+    }
+    Ok(ids)
+}
+
+#[test]
+fn querying_with_a_tag_filters_entities() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register_tag("enemy")?;
+    world.register_tag("player")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_tag("player")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_tag("enemy")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(20.0, 20.0))?
+        .with_tag("enemy")?;
+
+    let query = world.query(vec!["location", "enemy"])?;
+    let locations = query.get("location").unwrap();
+    let location: Ref<Point> = locations[0].try_borrow("location")?;
+
+    assert_eq!(locations.len(), 2);
+    assert_eq!(*location, Point::new(10.0, 10.0));
+    assert!(query.get("enemy").unwrap().is_empty());
+    Ok(())
+}
+
+#[test]
+fn toggling_tags() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register_tag("enemy")?;
+    world.register_tag("player")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_tag("player")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_tag("enemy")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(20.0, 20.0))?
+        .with_tag("enemy")?;
+
+    world.untag(1, "enemy")?;
+    world.tag(0, "enemy")?;
+
+    assert_eq!(queried_ids(&world, "enemy")?, vec![0, 2]);
+    assert!(world.has_tag(0, "enemy")?);
+    assert!(!world.has_tag(1, "enemy")?);
+    Ok(())
+}
+
+#[test]
+fn deleting_tagged_entities() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register_tag("enemy")?;
+    world.register_tag("player")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_tag("player")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_tag("enemy")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(20.0, 20.0))?
+        .with_tag("enemy")?;
+
+    world.delete_by_id(1)?;
+    world.update()?;
+
+    assert_eq!(queried_ids(&world, "enemy")?, vec![2]);
+    assert_eq!(*world.get::<Point>(2, "location")?, Point::new(20.0, 20.0));
+    Ok(())
+}
+
+#[test]
+fn tags_do_not_store_data() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register_tag("enemy")?;
+    world.register_tag("player")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_tag("player")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_tag("enemy")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(20.0, 20.0))?
+        .with_tag("enemy")?;
+
+    assert!(matches!(
+        world.spawn_entity()?.with_component("enemy", 5_u32),
+        Err(BbEcsError::TagHasNoData { .. })
+    ));
+    assert!(matches!(
+        world.get::<u32>(1, "enemy"),
+        Err(BbEcsError::TagHasNoData { .. })
+    ));
+    assert!(matches!(
+        world.tag(1, "location"),
+        Err(BbEcsError::NotATag { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn querying_with_a_tag_through_the_query_macro() -> Result<()> {
+    let mut world = World::new();
+    world.register("location")?;
+    world.register_tag("enemy")?;
+    world.register_tag("player")?;
+
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(0.0, 0.0))?
+        .with_tag("player")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(10.0, 10.0))?
+        .with_tag("enemy")?;
+    world
+        .spawn_entity()?
+        .with_component("location", Point::new(20.0, 20.0))?
+        .with_tag("enemy")?;
+
+    let query;
+    let (locations, enemies) = bbecs::query!(world, query, "location", "enemy");
+
+    assert_eq!(locations.len(), 2);
+    assert!(enemies.is_empty());
+    Ok(())
+}
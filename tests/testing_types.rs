@@ -58,7 +58,20 @@ test_world_by_type!(u32, testing_u32s);
 test_world_by_type!(usize, testing_usizes);
 test_world_by_type!(bool, testing_booleans);
 test_world_by_type!(String, testing_markers);
-// Color(Color), // doesn't implement default
+test_world_by_type!(bbecs::data_types::color::Color, testing_colors);
+test_world_by_type!(
+    bbecs::data_types::entity_ref::EntityRef,
+    testing_entity_refs
+);
+test_world_by_type!(Vec<bbecs::data_types::point::Point>, testing_point_lists);
+test_world_by_type!(Vec<f32>, testing_f32_lists);
+test_world_by_type!(bbecs::data_types::timer::Timer, testing_timers);
+test_world_by_type!(bbecs::data_types::rect::Rect, testing_rects);
+test_world_by_type!(bbecs::data_types::circle::Circle, testing_circles);
+test_world_by_type!(bbecs::data_types::transform::Transform, testing_transforms);
+test_world_by_type!(bbecs::data_types::collider::Collider, testing_colliders);
+test_world_by_type!(bbecs::data_types::boundary::Boundary, testing_boundaries);
+// GgezColor(Color), // doesn't implement default
 // Mesh(Mesh), // doesn't implement default
 // GgezKeyCode(KeyCode), // doesn't implement default
 // GgezText(Text), // cannot compare text with each other
@@ -0,0 +1,65 @@
+use bbecs::data_types::timer::{Timer, TimerMode};
+use bbecs::errors::BbEcsError;
+use bbecs::world::{World, WorldMethods};
+use eyre::Result;
+
+const DELTA_TIME: &str = "delta time";
+
+#[test]
+fn ticking_timers_uses_the_delta_time_resource() -> Result<()> {
+    let mut world = World::new();
+    world.register("cooldown")?;
+    world.register("spawner")?;
+    world.add_resource(DELTA_TIME.to_owned(), 0.25_f32);
+
+    world
+        .spawn_entity()?
+        .with_component("cooldown", Timer::once(0.5))?;
+    world
+        .spawn_entity()?
+        .with_component("spawner", Timer::new(0.25, TimerMode::Repeating))?;
+
+    world.tick_timers(DELTA_TIME)?;
+
+    let cooldown = world.get::<Timer>(0, "cooldown")?;
+    assert!((cooldown.elapsed() - 0.25).abs() < f32::EPSILON);
+    assert!(!cooldown.just_finished());
+    assert!(world.get::<Timer>(1, "spawner")?.just_finished());
+    Ok(())
+}
+
+#[test]
+fn timers_only_just_finish_for_one_tick() -> Result<()> {
+    let mut world = World::new();
+    world.register("cooldown")?;
+    world.register("spawner")?;
+    world.add_resource(DELTA_TIME.to_owned(), 0.25_f32);
+
+    world
+        .spawn_entity()?
+        .with_component("cooldown", Timer::once(0.5))?;
+    world
+        .spawn_entity()?
+        .with_component("spawner", Timer::new(0.25, TimerMode::Repeating))?;
+
+    world.tick_timers(DELTA_TIME)?;
+    world.tick_timers(DELTA_TIME)?;
+    assert!(world.get::<Timer>(0, "cooldown")?.just_finished());
+
+    world.tick_timers(DELTA_TIME)?;
+    let cooldown = world.get::<Timer>(0, "cooldown")?;
+    assert!(!cooldown.just_finished());
+    assert!(cooldown.finished());
+    assert!(world.get::<Timer>(1, "spawner")?.just_finished());
+    Ok(())
+}
+
+#[test]
+fn ticking_timers_without_a_delta_time_resource() {
+    let world = World::new();
+
+    assert!(matches!(
+        world.tick_timers(DELTA_TIME),
+        Err(BbEcsError::ResourceNotFound { .. })
+    ));
+}